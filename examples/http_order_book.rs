@@ -0,0 +1,21 @@
+use bybit::{http, rest::*, Result};
+
+const SYMBOL: &str = "BTCUSD";
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    println!("printing the order book for {}", SYMBOL);
+
+    // safe to unwrap because we know url is valid
+    let client = http::Client::new(http::MAINNET_BYBIT, "", "").unwrap();
+    let book = client.fetch_order_book(SYMBOL).await?;
+
+    for ask in book.asks.iter().rev() {
+        println!("{:?}", ask);
+    }
+    for bid in book.bids.iter() {
+        println!("{:?}", bid);
+    }
+
+    Ok(())
+}
@@ -17,7 +17,7 @@ async fn main() -> Result<()> {
     let client = http::Client::new(http::MAINNET_BYBIT, api_key, api_secret).unwrap();
     let wallets = client.fetch_wallets().await?;
     for currency in wallets.currencies() {
-        let wallet = wallets.get(&currency).unwrap();
+        let wallet = wallets.get(currency).unwrap();
         println!("{}: {}", currency, wallet.wallet_balance);
     }
 
@@ -13,14 +13,21 @@ async fn main() -> Result<()> {
         .subscribe(&[ws::Channel::OrderBook25(SYMBOL.to_owned())])
         .await?;
 
+    let mut books = ws::OrderBookStream::new(client);
     for _ in 0..5 {
-        match client.next().await {
-            Some(Ok(data)) => println!("{:?}", data),
+        match books.next().await {
+            Some(Ok(book)) => println!(
+                "best bid: {:?}, best ask: {:?}, spread: {:?}",
+                book.best_bid(),
+                book.best_ask(),
+                book.spread()
+            ),
+            Some(Err(ws::Error::OrderBookOutOfSync(symbol))) => books.resync(&symbol).await?,
             _ => (),
         }
     }
 
-    client.disconnect().await?;
+    books.client_mut().disconnect().await?;
 
     Ok(())
 }
@@ -6,13 +6,13 @@ async fn main() -> Result<()> {
     println!("printing the next 5 trades");
 
     let mut client = ws::Client::new(ws::MAINNET_BYBIT, "", "");
+    client.set_reconnect_policy(ws::ReconnectPolicy::default());
     client.connect().await?;
     client.subscribe(&[ws::Channel::Trade]).await?;
 
     for _ in 0..5 {
-        match client.next().await {
-            Some(Ok(ws::Data::Trade(trade))) => println!("{:?}", trade),
-            _ => (),
+        if let Some(Ok(ws::Data::Trade(trade))) = client.next().await {
+            println!("{:?}", trade);
         }
     }
 
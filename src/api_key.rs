@@ -0,0 +1,138 @@
+use crate::deserialize::string_or_number;
+use serde::{Deserialize, Serialize};
+use thiserror::Error as ThisError;
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ApiKeyPermission {
+    Order,
+    Position,
+    SpotTrade,
+    AccountTransfer,
+    SubMemberTransfer,
+    Withdraw,
+    Exchange,
+    /// A permission unknown to this crate
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ApiKeyInfo {
+    /// API key
+    pub api_key: String,
+    /// Key type (`personal` or `third_party`)
+    #[serde(rename = "type")]
+    pub key_type: String,
+    /// User id
+    pub user_id: i64,
+    /// Whitelisted IP addresses, `*` if the key is not restricted to any IP address
+    pub ips: Vec<String>,
+    /// Note
+    pub note: String,
+    /// Permissions
+    pub permissions: Vec<ApiKeyPermission>,
+    /// Creation time (RFC 3339)
+    pub created_at: String,
+    /// Expiry time (RFC 3339), empty if the key never expires
+    #[serde(default)]
+    pub expired_at: String,
+    /// Read only
+    pub read_only: bool,
+}
+
+/// The reason an API key was rejected by `ApiKeyInfo::check_permissions`.
+#[derive(Debug, Clone, PartialEq, ThisError)]
+pub enum ApiKeyError {
+    #[error("API key {0} not found")]
+    NotFound(String),
+    #[error("API key is read only")]
+    ReadOnly,
+    #[error("API key is missing the {0:?} permission")]
+    MissingPermission(ApiKeyPermission),
+    #[error("API key has the unexpected {0:?} permission")]
+    UnexpectedPermission(ApiKeyPermission),
+}
+
+impl ApiKeyInfo {
+    /// Returns `true` if the key can only be used from whitelisted IP addresses.
+    pub fn is_ip_restricted(&self) -> bool {
+        !self.ips.is_empty() && !self.ips.iter().any(|ip| ip == "*")
+    }
+
+    /// Check that the key is not read only and has exactly the given permissions.
+    /// * `permissions` - The expected permissions.
+    pub fn check_permissions(&self, permissions: &[ApiKeyPermission]) -> Result<(), ApiKeyError> {
+        if self.read_only {
+            return Err(ApiKeyError::ReadOnly);
+        }
+        if let Some(missing) = permissions
+            .iter()
+            .find(|permission| !self.permissions.contains(permission))
+        {
+            return Err(ApiKeyError::MissingPermission(*missing));
+        }
+        if let Some(unexpected) = self
+            .permissions
+            .iter()
+            .find(|permission| !permissions.contains(permission))
+        {
+            return Err(ApiKeyError::UnexpectedPermission(*unexpected));
+        }
+        Ok(())
+    }
+}
+
+/// The liquidity contribution points of a day.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct LcpInfo {
+    /// Date (YYYY-MM-DD)
+    pub date: String,
+    /// Personal liquidity contribution ratio
+    #[serde(deserialize_with = "string_or_number")]
+    pub self_ratio: f64,
+    /// Liquidity contribution ratio of the platform
+    #[serde(deserialize_with = "string_or_number")]
+    pub platform_ratio: f64,
+    /// Liquidity contribution points
+    #[serde(deserialize_with = "string_or_number")]
+    pub score: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_permissions() {
+        let info: ApiKeyInfo = serde_json::from_str(
+            r#"{"api_key":"7GkMBBLVNFYAmmWYZt","type":"personal","user_id":1,"inviter_id":0,
+                "ips":["*"],"note":"trading","permissions":["Order","Position","Unknown"],
+                "created_at":"2019-10-28T13:22:39.000Z","expired_at":"2020-01-28T13:22:39.000Z",
+                "read_only":false,"vip_level":"","mkt_maker_level":"","affiliate_id":0}"#,
+        )
+        .unwrap();
+        assert!(!info.is_ip_restricted());
+        assert_eq!(info.permissions[2], ApiKeyPermission::Other);
+        assert_eq!(
+            info.check_permissions(&[ApiKeyPermission::Order, ApiKeyPermission::Withdraw]),
+            Err(ApiKeyError::MissingPermission(ApiKeyPermission::Withdraw))
+        );
+        assert_eq!(
+            info.check_permissions(&[ApiKeyPermission::Order, ApiKeyPermission::Position]),
+            Err(ApiKeyError::UnexpectedPermission(ApiKeyPermission::Other))
+        );
+
+        let info = ApiKeyInfo {
+            permissions: vec![ApiKeyPermission::Order, ApiKeyPermission::Position],
+            ..info
+        };
+        assert!(info
+            .check_permissions(&[ApiKeyPermission::Position, ApiKeyPermission::Order])
+            .is_ok());
+        let info = ApiKeyInfo {
+            read_only: true,
+            ..info
+        };
+        assert_eq!(info.check_permissions(&[]), Err(ApiKeyError::ReadOnly));
+    }
+}
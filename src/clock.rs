@@ -0,0 +1,130 @@
+use crate::{
+    http::{self, Client},
+    rest::ServerTime,
+};
+use chrono::{DateTime, Duration, Utc};
+use std::sync::{
+    atomic::{AtomicI64, Ordering},
+    Arc,
+};
+use tokio::task::JoinHandle;
+use tokio::time;
+
+/// Number of server time samples taken per synchronization, the one with the lowest round trip
+/// time is used.
+const SYNC_SAMPLES: usize = 3;
+
+/// A clock that tracks the offset between the local clock and the Bybit server clock.
+/// Clones share the same offset, so a single clock can be used by multiple clients.
+#[derive(Debug, Clone, Default)]
+pub struct Clock {
+    offset_ms: Arc<AtomicI64>,
+}
+
+impl Clock {
+    /// Create a new clock without any offset.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns the offset (in milliseconds) of the server clock relative to the local clock.
+    pub fn offset_ms(&self) -> i64 {
+        self.offset_ms.load(Ordering::Relaxed)
+    }
+
+    /// Set the offset (in milliseconds) of the server clock relative to the local clock.
+    /// * `offset_ms` - The offset.
+    pub fn set_offset_ms(&self, offset_ms: i64) {
+        self.offset_ms.store(offset_ms, Ordering::Relaxed);
+    }
+
+    /// Returns the current time, according to the server clock.
+    pub fn now(&self) -> DateTime<Utc> {
+        Utc::now() + Duration::milliseconds(self.offset_ms())
+    }
+
+    /// Returns the current timestamp (in milliseconds), according to the server clock.
+    pub fn timestamp_millis(&self) -> i64 {
+        self.now().timestamp_millis()
+    }
+
+    /// Measure the offset of the server clock and update this clock.
+    /// The round trip time of the request is compensated for.
+    /// * `client` - The client used to fetch the server time.
+    pub async fn sync(&self, client: &Client) -> http::Result<i64> {
+        let mut best: Option<(i64, i64)> = None;
+        for _ in 0..SYNC_SAMPLES {
+            let sent = Utc::now().timestamp_millis();
+            let server_time = client.server_time().await?;
+            let received = Utc::now().timestamp_millis();
+
+            let server_ms = match parse_time_now(&server_time) {
+                Some(server_ms) => server_ms,
+                None => continue,
+            };
+            let rtt = received - sent;
+            let offset = server_ms - (sent + rtt / 2);
+            if best.is_none_or(|(best_rtt, _)| rtt < best_rtt) {
+                best = Some((rtt, offset));
+            }
+        }
+
+        let offset = best
+            .map(|(_, offset)| offset)
+            .unwrap_or_else(|| self.offset_ms());
+        self.set_offset_ms(offset);
+        Ok(offset)
+    }
+
+    /// Spawn a task that synchronizes this clock on the given interval.
+    /// Failed synchronizations keep the previous offset.
+    /// * `client` - The client used to fetch the server time.
+    /// * `interval` - The interval between two synchronizations.
+    pub fn spawn_sync(&self, client: Client, interval: std::time::Duration) -> JoinHandle<()> {
+        let clock = self.clone();
+        tokio::spawn(async move {
+            let mut interval = time::interval(interval);
+            loop {
+                interval.tick().await;
+                let _res = clock.sync(&client).await;
+            }
+        })
+    }
+}
+
+/// Parse the `time_now` of a response (seconds, e.g. `1577444332.192859`) into milliseconds.
+fn parse_time_now(time_now: &str) -> Option<i64> {
+    let (secs, fraction) = match time_now.split_once('.') {
+        Some((secs, fraction)) => (secs, fraction),
+        None => (time_now, ""),
+    };
+    let secs: i64 = secs.parse().ok()?;
+    let millis: String = fraction.chars().chain("000".chars()).take(3).collect();
+    let millis: i64 = millis.parse().ok()?;
+    Some(secs * 1000 + millis)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn time_now() {
+        assert_eq!(parse_time_now("1577444332.192859"), Some(1577444332192));
+        assert_eq!(parse_time_now("1577444332.1"), Some(1577444332100));
+        assert_eq!(parse_time_now("1577444332"), Some(1577444332000));
+        assert_eq!(parse_time_now("invalid"), None);
+    }
+
+    #[test]
+    fn offset() {
+        let clock = Clock::new();
+        let shared = clock.clone();
+        clock.set_offset_ms(-5000);
+        assert_eq!(shared.offset_ms(), -5000);
+
+        let server_ms = shared.timestamp_millis();
+        let drift = Utc::now().timestamp_millis() - server_ms;
+        assert!((5000..5100).contains(&drift));
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{
+    deserialize::string_or_number, ContractType, ExecType, OrderId, OrderType, Price, Qty, Side,
+};
+use serde::{Deserialize, Serialize};
+
+/// The realised profit and loss of closing (part of) a position.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ClosedPnl {
+    /// Record id
+    pub id: i64,
+    /// Symbol
+    pub symbol: String,
+    /// Id of the closing order
+    pub order_id: OrderId,
+    /// Side of the closing order
+    pub side: Side,
+    /// Quantity of the closing order
+    pub qty: Qty,
+    /// Price of the closing order
+    pub order_price: Price,
+    /// Type of the closing order
+    pub order_type: OrderType,
+    /// Execution type
+    pub exec_type: ExecType,
+    /// Closed quantity
+    pub closed_size: Qty,
+    /// Cumulative value of the entry executions
+    #[serde(deserialize_with = "string_or_number")]
+    pub cum_entry_value: f64,
+    /// Average entry price
+    pub avg_entry_price: Price,
+    /// Cumulative value of the exit executions
+    #[serde(deserialize_with = "string_or_number")]
+    pub cum_exit_value: f64,
+    /// Average exit price
+    pub avg_exit_price: Price,
+    /// Realised profit and loss, net of fees
+    #[serde(deserialize_with = "string_or_number")]
+    pub closed_pnl: f64,
+    /// Number of executions of the closing order
+    pub fill_count: i64,
+    /// Leverage
+    #[serde(deserialize_with = "string_or_number")]
+    pub leverage: f64,
+    /// Creation time (in seconds)
+    pub created_at: i64,
+}
+
+impl ClosedPnl {
+    /// Returns the profit and loss before fees, derived from the entry and exit values.
+    /// Values of inverse contracts are in the base currency, so a long position profits when the
+    /// exit value is lower than the entry value.
+    /// * `contract_type` - The type of the contract of the symbol.
+    pub fn gross_pnl(&self, contract_type: ContractType) -> f64 {
+        let long_pnl = if contract_type.is_inverse() {
+            self.cum_entry_value - self.cum_exit_value
+        } else {
+            self.cum_exit_value - self.cum_entry_value
+        };
+        match self.side {
+            // A sell order closes a long position.
+            Side::Sell => long_pnl,
+            Side::Buy => -long_pnl,
+        }
+    }
+
+    /// Returns the fees paid to open and close the position, negative for net rebates.
+    /// * `contract_type` - The type of the contract of the symbol.
+    pub fn fees(&self, contract_type: ContractType) -> f64 {
+        self.gross_pnl(contract_type) - self.closed_pnl
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let closed_pnl: ClosedPnl = serde_json::from_str(
+            r#"{"id":5652,"user_id":1,"symbol":"BTCUSDT",
+                "order_id":"bd1844f-f3c0-4e10-8c25-10fea03763f6","side":"Sell","qty":0.01,"order_price":9790.5,"order_type":"Market",
+                "exec_type":"Trade","closed_size":0.01,"cum_entry_value":97.97,
+                "avg_entry_price":9797,"cum_exit_value":98.5,"avg_exit_price":9850,
+                "closed_pnl":0.39,"fill_count":1,"leverage":10,"created_at":1591186521}"#,
+        )
+        .unwrap();
+        assert_eq!(closed_pnl.closed_size, "0.01".parse().unwrap());
+        let contract_type = ContractType::LinearPerpetual;
+        assert!((closed_pnl.gross_pnl(contract_type) - 0.53).abs() < 1e-9);
+        assert!((closed_pnl.fees(contract_type) - 0.14).abs() < 1e-9);
+
+        let short = ClosedPnl {
+            side: Side::Buy,
+            cum_entry_value: 0.0001,
+            cum_exit_value: 0.00009,
+            closed_pnl: -0.000011,
+            ..closed_pnl
+        };
+        let contract_type = ContractType::InversePerpetual;
+        assert!((short.gross_pnl(contract_type) + 0.00001).abs() < 1e-12);
+    }
+}
@@ -1,14 +1,20 @@
+use crate::{Price, Qty};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Copy, Clone, Debug)]
+/// Month codes used in the names of inverse futures, e.g. `Z` in `BTCUSDZ22`.
+const FUTURES_MONTH_CODES: &str = "FGHJKMNQUVXZ";
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq)]
 pub enum ContractType {
     InversePerpetual,
     LinearPerpetual,
     InverseFutures,
 }
 
-#[derive(Serialize, Deserialize, Copy, Clone, Debug)]
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Default)]
 pub enum ContractStatus {
+    #[default]
     Trading,
     Settling,
     Closed,
@@ -16,8 +22,111 @@ pub enum ContractStatus {
     Pending,
 }
 
-impl Default for ContractStatus {
-    fn default() -> Self {
-        ContractStatus::Trading
+impl ContractType {
+    /// Classify a symbol by its name only, e.g. `BTCUSD`, `BTCUSDT` or `BTCUSDZ22`.
+    /// Prefer `Symbol::contract_type` when the symbol metadata is available.
+    /// * `name` - The name of the symbol.
+    pub fn from_name(name: &str) -> Self {
+        if name.ends_with("USDT") {
+            ContractType::LinearPerpetual
+        } else if has_expiry(name) {
+            ContractType::InverseFutures
+        } else {
+            ContractType::InversePerpetual
+        }
+    }
+
+    /// Returns `true` if the contract is margined and settled in the base currency.
+    /// The quantity of inverse contracts is expressed in the quote currency (e.g. USD).
+    pub fn is_inverse(&self) -> bool {
+        !self.is_linear()
+    }
+
+    /// Returns `true` if the contract is margined and settled in the quote currency.
+    /// The quantity of linear contracts is expressed in the base currency (e.g. BTC).
+    pub fn is_linear(&self) -> bool {
+        matches!(self, ContractType::LinearPerpetual)
+    }
+
+    /// Returns `true` if the contract has an expiry date.
+    pub fn is_futures(&self) -> bool {
+        matches!(self, ContractType::InverseFutures)
+    }
+
+    /// Returns the value of an order in the margin currency.
+    /// Returns `None` for inverse contracts at a zero price.
+    /// * `qty` - The quantity of the order.
+    /// * `price` - The price of the order.
+    pub fn order_value(&self, qty: Qty, price: Price) -> Option<Decimal> {
+        if self.is_linear() {
+            Some(qty * price)
+        } else {
+            qty.value().checked_div(price.value())
+        }
+    }
+}
+
+/// Returns `true` if the name ends with an expiry, either a month code followed by a two digit
+/// year (e.g. `BTCUSDZ22`) or a month and day (e.g. the alias `BTCUSD0625`).
+pub(crate) fn has_expiry(name: &str) -> bool {
+    let digits = name
+        .chars()
+        .rev()
+        .take_while(|c| c.is_ascii_digit())
+        .count();
+    match digits {
+        2 => name[..name.len() - 2]
+            .chars()
+            .last()
+            .is_some_and(|c| FUTURES_MONTH_CODES.contains(c)),
+        4 => name.len() > 4,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_name() {
+        assert_eq!(
+            ContractType::from_name("BTCUSD"),
+            ContractType::InversePerpetual
+        );
+        assert_eq!(
+            ContractType::from_name("BTCUSDT"),
+            ContractType::LinearPerpetual
+        );
+        assert_eq!(
+            ContractType::from_name("BTCUSDZ22"),
+            ContractType::InverseFutures
+        );
+        assert_eq!(
+            ContractType::from_name("BTCUSD0625"),
+            ContractType::InverseFutures
+        );
+        assert_eq!(
+            ContractType::from_name("1INCHUSDT"),
+            ContractType::LinearPerpetual
+        );
+    }
+
+    #[test]
+    fn order_value() {
+        let qty = Qty::from(100);
+        let price = Price::from(50);
+        assert_eq!(
+            ContractType::InversePerpetual.order_value(qty, price),
+            Some(Decimal::from(2))
+        );
+        assert_eq!(
+            ContractType::LinearPerpetual.order_value(qty, price),
+            Some(Decimal::from(5000))
+        );
+        assert_eq!(
+            ContractType::InverseFutures.order_value(qty, Price::ZERO),
+            None
+        );
     }
 }
@@ -0,0 +1,286 @@
+use rust_decimal::Decimal;
+use serde::{
+    de::{self, Deserializer, Visitor},
+    Deserialize, Serialize, Serializer,
+};
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+pub use rust_decimal::Error as DecimalError;
+
+macro_rules! decimal_type {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+        pub struct $name(Decimal);
+
+        impl $name {
+            /// Zero.
+            pub const ZERO: $name = $name(Decimal::ZERO);
+
+            /// Create a new value.
+            /// * `value` - The decimal value.
+            pub const fn new(value: Decimal) -> Self {
+                $name(value)
+            }
+
+            /// Create a new value from an `f64`, using its shortest representation
+            /// (e.g. `0.1` becomes exactly `0.1`).
+            /// Returns `None` if the value is not finite or out of range.
+            /// * `value` - The value to convert.
+            pub fn from_f64(value: f64) -> Option<Self> {
+                decimal_from_f64(value).map($name)
+            }
+
+            /// Returns the decimal value.
+            pub fn value(&self) -> Decimal {
+                self.0
+            }
+
+            /// Returns the value as an `f64`, which may lose precision.
+            pub fn to_f64(&self) -> f64 {
+                self.0.to_string().parse().unwrap_or_default()
+            }
+
+            /// Returns `true` if the value is zero.
+            pub fn is_zero(&self) -> bool {
+                self.0.is_zero()
+            }
+        }
+
+        impl From<Decimal> for $name {
+            fn from(value: Decimal) -> Self {
+                $name(value)
+            }
+        }
+
+        impl From<$name> for Decimal {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl From<i64> for $name {
+            fn from(value: i64) -> Self {
+                $name(Decimal::from(value))
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = DecimalError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Decimal::from_str(s).map($name)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0.normalize(), f)
+            }
+        }
+
+        impl Add for $name {
+            type Output = $name;
+
+            fn add(self, rhs: $name) -> $name {
+                $name(self.0 + rhs.0)
+            }
+        }
+
+        impl AddAssign for $name {
+            fn add_assign(&mut self, rhs: $name) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl Sub for $name {
+            type Output = $name;
+
+            fn sub(self, rhs: $name) -> $name {
+                $name(self.0 - rhs.0)
+            }
+        }
+
+        impl SubAssign for $name {
+            fn sub_assign(&mut self, rhs: $name) {
+                self.0 -= rhs.0;
+            }
+        }
+
+        impl Neg for $name {
+            type Output = $name;
+
+            fn neg(self) -> $name {
+                $name(-self.0)
+            }
+        }
+
+        impl Mul<Decimal> for $name {
+            type Output = $name;
+
+            fn mul(self, rhs: Decimal) -> $name {
+                $name(self.0 * rhs)
+            }
+        }
+
+        impl Div<Decimal> for $name {
+            type Output = $name;
+
+            fn div(self, rhs: Decimal) -> $name {
+                $name(self.0 / rhs)
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserializer.deserialize_any(DecimalVisitor).map($name)
+            }
+        }
+    };
+}
+
+decimal_type!(
+    /// An exact decimal price.
+    /// Serialized as a string, deserialized from either a string or a number.
+    Price
+);
+
+decimal_type!(
+    /// An exact decimal quantity.
+    /// Serialized as a string, deserialized from either a string or a number.
+    Qty
+);
+
+impl Mul<Qty> for Price {
+    type Output = Decimal;
+
+    fn mul(self, rhs: Qty) -> Decimal {
+        self.0 * rhs.0
+    }
+}
+
+impl Mul<Price> for Qty {
+    type Output = Decimal;
+
+    fn mul(self, rhs: Price) -> Decimal {
+        self.0 * rhs.0
+    }
+}
+
+pub(crate) fn decimal_from_f64(value: f64) -> Option<Decimal> {
+    if !value.is_finite() {
+        return None;
+    }
+    let repr = value.to_string();
+    Decimal::from_str(&repr)
+        .or_else(|_| Decimal::from_scientific(&format!("{:e}", value)))
+        .ok()
+}
+
+/// Deserializes a decimal from either a string or a JSON number.
+pub(crate) struct DecimalVisitor;
+
+impl<'de> Visitor<'de> for DecimalVisitor {
+    type Value = Decimal;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("either a decimal string or a JSON number")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Decimal::from_str(value)
+            .or_else(|_| Decimal::from_scientific(value))
+            .map_err(|e| E::custom(format!("Failed to parse {} as decimal: {}", value, e)))
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        decimal_from_f64(value)
+            .ok_or_else(|| E::custom(format!("Failed to convert {} to decimal", value)))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Decimal::from(value))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Decimal::from(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Deserialize, Serialize)]
+    struct Level {
+        price: Price,
+        qty: Qty,
+        #[serde(deserialize_with = "crate::deserialize::optional_decimal", default)]
+        last_price: Option<Price>,
+    }
+
+    #[test]
+    fn deserialize() {
+        let level: Level =
+            serde_json::from_str(r#"{"price":"45420.5","qty":10,"last_price":""}"#).unwrap();
+        assert_eq!(level.price, "45420.5".parse().unwrap());
+        assert_eq!(level.qty, Qty::from(10));
+        assert_eq!(level.last_price, None);
+
+        let level: Level =
+            serde_json::from_str(r#"{"price":0.1,"qty":"0.001","last_price":8098.5}"#).unwrap();
+        assert_eq!(level.price.to_string(), "0.1");
+        assert_eq!(level.qty.to_string(), "0.001");
+        assert_eq!(level.last_price, Price::from_f64(8098.5));
+
+        assert!(serde_json::from_str::<Level>(r#"{"price":"","qty":1}"#).is_err());
+    }
+
+    #[test]
+    fn serialize() {
+        let level = Level {
+            price: Price::from_f64(45420.0).unwrap(),
+            qty: "1.50".parse().unwrap(),
+            last_price: None,
+        };
+        assert_eq!(
+            serde_urlencoded::to_string(&level).unwrap(),
+            "price=45420&qty=1.5"
+        );
+    }
+
+    #[test]
+    fn exact_arithmetic() {
+        let tick = Price::from_f64(0.1).unwrap();
+        let mut price = Price::from(45420);
+        for _ in 0..10 {
+            price += tick;
+        }
+        assert_eq!(price.to_string(), "45421");
+        assert_eq!(
+            Price::from_f64(0.1).unwrap() * Qty::from(3),
+            "0.3".parse().unwrap()
+        );
+        assert_eq!(Price::from_f64(f64::NAN), None);
+    }
+}
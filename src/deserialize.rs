@@ -1,3 +1,4 @@
+use crate::decimal::DecimalVisitor;
 use serde::de::{self, Deserializer, Visitor};
 
 pub fn string_or_number<'de, D>(deserializer: D) -> Result<f64, D::Error>
@@ -85,3 +86,164 @@ impl<'de> Visitor<'de> for OptionalStringOrNumber {
         deserializer.deserialize_any(StringOrNumber).map(Some)
     }
 }
+
+/// Deserializes an optional decimal (e.g. `Option<Price>`), treating `null` and `""` as `None`.
+pub fn optional_decimal<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: From<rust_decimal::Decimal>,
+{
+    deserializer
+        .deserialize_option(OptionalDecimal)
+        .map(|value| value.map(T::from))
+}
+
+struct OptionalDecimal;
+
+impl<'de> Visitor<'de> for OptionalDecimal {
+    type Value = Option<rust_decimal::Decimal>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("either a decimal string, a JSON number, an empty string or null")
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if value.is_empty() {
+            return Ok(None);
+        }
+        DecimalVisitor.visit_str(value).map(Some)
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        DecimalVisitor.visit_f64(value).map(Some)
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        DecimalVisitor.visit_i64(value).map(Some)
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        DecimalVisitor.visit_u64(value).map(Some)
+    }
+}
+
+/// Deserializes an identifier that is either a string or an integer into a `String`.
+pub fn string_or_integer<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(StringOrInteger)
+}
+
+struct StringOrInteger;
+
+impl<'de> Visitor<'de> for StringOrInteger {
+    type Value = String;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("either a string or a JSON integer")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(value.to_owned())
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(value.to_string())
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(value.to_string())
+    }
+}
+
+/// Deserializes a time that is either a JSON integer (in seconds) or an RFC 3339 string into
+/// seconds.
+pub fn seconds_or_rfc3339<'de, D>(deserializer: D) -> Result<i64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(SecondsOrRfc3339)
+}
+
+struct SecondsOrRfc3339;
+
+impl<'de> Visitor<'de> for SecondsOrRfc3339 {
+    type Value = i64;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("either a JSON integer or an RFC 3339 string")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if let Ok(seconds) = value.parse() {
+            return Ok(seconds);
+        }
+        chrono::DateTime::parse_from_rfc3339(value)
+            .map(|time| time.timestamp())
+            .map_err(|e| E::custom(format!("Failed to parse {} as a time: {}", value, e)))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(value)
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(value as i64)
+    }
+}
+
+/// Deserializes an RFC 3339 time string into milliseconds.
+pub fn rfc3339_millis<'de, D>(deserializer: D) -> Result<i64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value: String = serde::Deserialize::deserialize(deserializer)?;
+    chrono::DateTime::parse_from_rfc3339(&value)
+        .map(|time| time.timestamp_millis())
+        .map_err(|e| de::Error::custom(format!("Failed to parse {} as a time: {}", value, e)))
+}
@@ -1,4 +1,4 @@
-use super::{http, ws};
+use super::{http, ws, ValidationError};
 use thiserror::Error as ThisError;
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -9,6 +9,8 @@ pub enum Error {
     Http(http::Error),
     #[error("Ws Error: {0}")]
     Ws(ws::Error),
+    #[error("Validation Error: {0}")]
+    Validation(ValidationError),
 }
 
 impl From<http::Error> for Error {
@@ -22,3 +24,9 @@ impl From<ws::Error> for Error {
         Self::Ws(err)
     }
 }
+
+impl From<ValidationError> for Error {
+    fn from(err: ValidationError) -> Self {
+        Self::Validation(err)
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{
+    deserialize::{optional_string_or_number, string_or_number},
+    ExecId, ExecType, LiquidityType, OrderId, OrderLinkId, OrderType, Price, Qty, Side,
+};
+use serde::{Deserialize, Serialize};
+
+/// A fill of an order, as returned by `rest::FetchExecutions` and the `Execution` ws channel.
+/// Fields only reported by the REST API are optional.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Execution {
+    /// Execution id
+    pub exec_id: ExecId,
+    /// Order id
+    pub order_id: OrderId,
+    /// Order link id
+    pub order_link_id: OrderLinkId,
+    /// Symbol
+    pub symbol: String,
+    /// Order side
+    pub side: Side,
+    /// Order type
+    pub order_type: Option<OrderType>,
+    /// Order price
+    pub order_price: Option<Price>,
+    /// Order quantity
+    pub order_qty: Qty,
+    /// Execution type
+    pub exec_type: ExecType,
+    /// Execution price
+    pub exec_price: Price,
+    /// Execution quantity
+    pub exec_qty: Qty,
+    /// Execution value
+    #[serde(deserialize_with = "optional_string_or_number", default)]
+    pub exec_value: Option<f64>,
+    /// Execution fee, negative for rebates
+    #[serde(deserialize_with = "string_or_number")]
+    pub exec_fee: f64,
+    /// Fee rate
+    #[serde(deserialize_with = "optional_string_or_number", default)]
+    pub fee_rate: Option<f64>,
+    /// Remaining quantity of the order
+    pub leaves_qty: Qty,
+    /// Quantity of the position closed by this execution
+    pub closed_size: Option<Qty>,
+    /// Liquidity type
+    pub last_liquidity_ind: LiquidityType,
+    /// Execution time (in milliseconds)
+    pub trade_time_ms: i64,
+}
+
+impl Execution {
+    /// Returns `true` if the execution added liquidity to the order book.
+    pub fn is_maker(&self) -> bool {
+        self.last_liquidity_ind == LiquidityType::AddedLiquidity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let inverse: Execution = serde_json::from_str(
+            r#"{"closed_size":0,"cross_seq":277136382,"exec_fee":"0.0000001",
+                "exec_id":"256e5ef8-abfe-5772-971b-f944e15e0d68","exec_price":"8178.5",
+                "exec_qty":1,"exec_time":"1571676941.70682","exec_type":"Trade",
+                "exec_value":"0.00012227","fee_rate":"0.00075",
+                "last_liquidity_ind":"RemovedLiquidity","leaves_qty":0,"nth_fill":2,
+                "order_id":"7ad50cb1-9ad0-4f74-804b-d82a516e1029","order_link_id":"",
+                "order_price":"8178","order_qty":1,"order_type":"Market","side":"Buy",
+                "symbol":"BTCUSD","user_id":1,"trade_time_ms":1577480599000}"#,
+        )
+        .unwrap();
+        assert_eq!(inverse.exec_price, "8178.5".parse().unwrap());
+        assert!(!inverse.is_maker());
+
+        let linear: Execution = serde_json::from_str(
+            r#"{"order_id":"7369b2f4-52f1-4698-abf7-368e4ba9aefa","order_link_id":"",
+                "side":"Buy","symbol":"BTCUSDT","exec_id":"9b8216fa-98d7-55c0-b5fa-279db5727996",
+                "price":5720,"order_price":5720,"order_qty":0.001,"order_type":"Limit",
+                "fee_rate":-0.00025,"exec_price":5720,"exec_type":"Trade","exec_qty":0.001,
+                "exec_fee":-0.00143,"exec_value":5.72,"leaves_qty":0,"closed_size":0,
+                "last_liquidity_ind":"AddedLiquidity","trade_time":1585547384,
+                "trade_time_ms":1585547384847}"#,
+        )
+        .unwrap();
+        assert_eq!(linear.exec_qty, "0.001".parse().unwrap());
+        assert!(linear.is_maker());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::{Price, Qty};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Clone, Debug, Default)]
@@ -9,14 +10,14 @@ pub struct LeverageFilter {
 
 #[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct PriceFilter {
-    pub min_price: String,
-    pub max_price: String,
-    pub tick_size: String,
+    pub min_price: Price,
+    pub max_price: Price,
+    pub tick_size: Price,
 }
 
 #[derive(Serialize, Deserialize, Copy, Clone, Debug, Default)]
 pub struct LotSizeFilter {
-    pub min_trading_qty: f64,
-    pub max_trading_qty: f64,
-    pub qty_step: f64,
+    pub min_trading_qty: Qty,
+    pub max_trading_qty: Qty,
+    pub qty_step: Qty,
 }
@@ -0,0 +1,80 @@
+use crate::{
+    deserialize::{seconds_or_rfc3339, string_or_number},
+    order::Side,
+    Qty,
+};
+use serde::{Deserialize, Serialize};
+
+/// The funding rate of the last funding interval of a symbol.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct FundingRate {
+    /// Symbol
+    pub symbol: String,
+    /// Funding rate
+    #[serde(deserialize_with = "string_or_number")]
+    pub funding_rate: f64,
+    /// Funding time (in seconds)
+    #[serde(deserialize_with = "seconds_or_rfc3339")]
+    pub funding_rate_timestamp: i64,
+}
+
+/// The funding fee of the last funding interval of a position.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct FundingFee {
+    /// Symbol
+    pub symbol: String,
+    /// Position side
+    pub side: Side,
+    /// Position size
+    pub size: Qty,
+    /// Funding rate
+    #[serde(deserialize_with = "string_or_number")]
+    pub funding_rate: f64,
+    /// Funding fee, positive if paid and negative if received
+    #[serde(deserialize_with = "string_or_number")]
+    pub exec_fee: f64,
+    /// Funding time (in seconds)
+    #[serde(alias = "exec_time", deserialize_with = "seconds_or_rfc3339")]
+    pub exec_timestamp: i64,
+}
+
+/// The predicted funding of the next funding interval of a position.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PredictedFunding {
+    /// Predicted funding rate
+    #[serde(deserialize_with = "string_or_number")]
+    pub predicted_funding_rate: f64,
+    /// Predicted funding fee, positive if paid and negative if received
+    #[serde(deserialize_with = "string_or_number")]
+    pub predicted_funding_fee: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let inverse: FundingRate = serde_json::from_str(
+            r#"{"symbol":"BTCUSD","funding_rate":"0.00010000","funding_rate_timestamp":1577433600}"#,
+        )
+        .unwrap();
+        assert_eq!(inverse.funding_rate, 0.0001);
+        assert_eq!(inverse.funding_rate_timestamp, 1_577_433_600);
+
+        let linear: FundingRate = serde_json::from_str(
+            r#"{"symbol":"BTCUSDT","funding_rate":0.0001,
+                "funding_rate_timestamp":"2019-12-27T08:00:00.000Z"}"#,
+        )
+        .unwrap();
+        assert_eq!(linear.funding_rate_timestamp, 1_577_433_600);
+
+        let fee: FundingFee = serde_json::from_str(
+            r#"{"symbol":"BTCUSDT","side":"Sell","size":0.01,"funding_rate":0.0001,
+                "exec_fee":-0.0007,"exec_time":"2019-12-27T08:00:00Z"}"#,
+        )
+        .unwrap();
+        assert_eq!(fee.size, "0.01".parse().unwrap());
+        assert_eq!(fee.exec_timestamp, 1_577_433_600);
+    }
+}
@@ -1,6 +1,12 @@
-use super::{Query, Response, Result, SignedQuery};
+use super::{
+    EndpointFamily, Query, RateLimit, RateLimiter, Response, Result, RetryPolicy, SignedQuery,
+};
+use crate::{Clock, ContractType, SymbolRegistry};
 use reqwest::Url;
 use serde::{de::DeserializeOwned, Serialize};
+use std::future::Future;
+use std::sync::Arc;
+use tokio::time;
 
 pub const MAINNET_BYBIT: &str = "https://api.bybit.com/";
 pub const MAINNET_BYTICK: &str = "https://api.bytick.com/";
@@ -12,6 +18,11 @@ pub struct Client {
     api_secret: String,
     client: reqwest::Client,
     base_url: Url,
+    rate_limiter: Arc<RateLimiter>,
+    retry_policy: RetryPolicy,
+    clock: Clock,
+    recv_window: Option<i64>,
+    symbol_registry: Option<SymbolRegistry>,
 }
 
 impl Client {
@@ -26,12 +37,161 @@ impl Client {
             api_secret: api_secret.to_owned(),
             client: reqwest::Client::new(),
             base_url,
+            rate_limiter: Arc::new(RateLimiter::new()),
+            retry_policy: RetryPolicy::none(),
+            clock: Clock::new(),
+            recv_window: None,
+            symbol_registry: None,
         })
     }
 
+    /// Returns the API key.
+    pub(crate) fn api_key(&self) -> &str {
+        &self.api_key
+    }
+
+    /// Returns the clock used to timestamp signed queries.
+    pub fn clock(&self) -> &Clock {
+        &self.clock
+    }
+
+    /// Set the clock used to timestamp signed queries.
+    /// * `clock` - The clock, see `Clock::sync` to synchronize it with the server.
+    pub fn set_clock(&mut self, clock: Clock) {
+        self.clock = clock;
+    }
+
+    /// Synchronize the clock of this client with the server clock.
+    /// Returns the measured offset in milliseconds.
+    pub async fn sync_clock(&self) -> Result<i64> {
+        self.clock.sync(self).await
+    }
+
+    /// Set the default `recv_window` (in milliseconds) of signed queries.
+    /// * `recv_window` - The receive window, `None` to use the server default.
+    pub fn set_recv_window(&mut self, recv_window: Option<i64>) {
+        self.recv_window = recv_window;
+    }
+
+    /// Returns the registry used to validate orders before they are placed.
+    pub fn symbol_registry(&self) -> Option<&SymbolRegistry> {
+        self.symbol_registry.as_ref()
+    }
+
+    /// Set the registry used to validate orders before they are placed.
+    /// Orders for symbols unknown to the registry are not validated.
+    /// * `registry` - The registry, `None` to disable validation.
+    pub fn set_symbol_registry(&mut self, registry: Option<SymbolRegistry>) {
+        self.symbol_registry = registry;
+    }
+
+    /// Returns the contract type of the given symbol, according to the registry if the symbol is
+    /// known, otherwise derived from the name of the symbol.
+    /// * `symbol` - The name of the symbol.
+    pub fn contract_type(&self, symbol: &str) -> ContractType {
+        self.symbol_registry
+            .as_ref()
+            .and_then(|registry| registry.get(symbol))
+            .map(|symbol| symbol.contract_type())
+            .unwrap_or_else(|| ContractType::from_name(symbol))
+    }
+
+    /// Returns the retry policy used for signed requests.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Set the retry policy used for signed requests.
+    /// Order creating requests are only retried if they carry an `order_link_id`.
+    /// * `policy` - The retry policy.
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        self.retry_policy = policy;
+    }
+
+    /// Returns the last known rate limit budget for the given endpoint family.
+    /// * `family` - The endpoint family.
+    pub fn rate_limit(&self, family: EndpointFamily) -> Option<RateLimit> {
+        self.rate_limiter.budget(family)
+    }
+
+    /// Set the length of the rate limit window (in milliseconds) assumed when a window resets,
+    /// until Bybit reports the actual reset time. Defaults to `DEFAULT_RATE_LIMIT_WINDOW_MS`.
+    /// * `window_ms` - The window length.
+    pub fn set_rate_limit_window_ms(&mut self, window_ms: i64) {
+        self.rate_limiter.set_window_ms(window_ms);
+    }
+
     /// Sign a query.
     pub fn sign_query<Q: Query>(&self, query: Q) -> SignedQuery<Q> {
-        SignedQuery::sign(query, self.api_key.as_str(), self.api_secret.as_str())
+        self.sign_query_with_recv_window(query, self.recv_window)
+    }
+
+    /// Sign a query with the given `recv_window`.
+    /// * `query` - The query to sign.
+    /// * `recv_window` - The receive window in milliseconds, `None` to use the server default.
+    pub fn sign_query_with_recv_window<Q: Query>(
+        &self,
+        query: Q,
+        recv_window: Option<i64>,
+    ) -> SignedQuery<Q> {
+        SignedQuery::with_recv_window(
+            query,
+            self.clock.timestamp_millis(),
+            recv_window,
+            self.api_key.as_str(),
+            self.api_secret.as_str(),
+        )
+    }
+
+    /// Sign the query and perform a GET request, retrying according to the retry policy.
+    /// The query is signed again (with a fresh timestamp) on every attempt.
+    /// * `path` - The path of the URL to request.
+    /// * `query` - The query to sign and send with the request.
+    pub async fn signed_get<Q: Query + Clone, T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: Q,
+    ) -> Result<Response<T>> {
+        self.retry(|_| {
+            let query = self.sign_query(query.clone());
+            async move { self.get(path, &query).await }
+        })
+        .await
+    }
+
+    /// Sign the query and perform a POST request, retrying according to the retry policy.
+    /// The query is signed again (with a fresh timestamp) on every attempt,
+    /// only use this for requests that are safe to repeat.
+    /// * `path` - The path of the URL to request.
+    /// * `query` - The query to sign and send with the request.
+    pub async fn signed_post<Q: Query + Clone, T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: Q,
+    ) -> Result<Response<T>> {
+        self.retry(|_| {
+            let query = self.sign_query(query.clone());
+            async move { self.post(path, &query).await }
+        })
+        .await
+    }
+
+    /// Repeatedly perform a request until it succeeds or the retry policy gives up.
+    /// * `request` - Performs the request, given the number of attempts made so far.
+    pub async fn retry<T, F, Fut>(&self, mut request: F) -> Result<Response<T>>
+    where
+        F: FnMut(u32) -> Fut,
+        Fut: Future<Output = Result<Response<T>>>,
+    {
+        let mut attempt = 0;
+        loop {
+            let result = request(attempt).await;
+            attempt += 1;
+            if !self.retry_policy.should_retry(attempt, &result) {
+                return result;
+            }
+            time::sleep(self.retry_policy.backoff(attempt)).await;
+        }
     }
 
     /// Perform a GET request and return the response.
@@ -43,6 +203,8 @@ impl Client {
         query: &Q,
     ) -> Result<Response<T>> {
         let url = self.base_url.join(path)?;
+        let family = EndpointFamily::from_path(path);
+        self.rate_limiter.acquire(family, &self.clock).await;
         let response = self
             .client
             .get(url)
@@ -50,7 +212,9 @@ impl Client {
             .send()
             .await?
             .error_for_status()?;
-        let result = response.json::<Response<T>>().await?;
+        let body = response.bytes().await?;
+        let result = Response::from_slice(&body)?;
+        self.update_rate_limit(family, &result);
         Ok(result)
     }
 
@@ -63,6 +227,8 @@ impl Client {
         query: &Q,
     ) -> Result<Response<T>> {
         let url = self.base_url.join(path)?;
+        let family = EndpointFamily::from_path(path);
+        self.rate_limiter.acquire(family, &self.clock).await;
         let response = self
             .client
             .post(url)
@@ -70,9 +236,20 @@ impl Client {
             .send()
             .await?
             .error_for_status()?;
-        let result = response.json::<Response<T>>().await?;
+        let body = response.bytes().await?;
+        let result = Response::from_slice(&body)?;
+        self.update_rate_limit(family, &result);
         Ok(result)
     }
+
+    fn update_rate_limit<T>(&self, family: EndpointFamily, response: &Response<T>) {
+        self.rate_limiter.update(
+            family,
+            response.rate_limit_status,
+            response.rate_limit,
+            response.rate_limit_reset_ms,
+        );
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
-use super::Response;
+use super::{BybitErrorKind, Response};
+use crate::{ApiKeyError, ValidationError};
 use thiserror::Error as ThisError;
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -9,13 +10,30 @@ pub enum Error {
     Url(url::ParseError),
     #[error("Reqwest Error: {0}")]
     Reqwest(reqwest::Error),
+    #[error("Json Error: {0}")]
+    Json(serde_json::Error),
     #[error("{0:?}")]
     ErrorCode(ErrorCode),
+    #[error("Validation Error: {0}")]
+    Validation(ValidationError),
+    #[error("Api Key Error: {0}")]
+    ApiKey(ApiKeyError),
+}
+
+impl Error {
+    /// Returns the kind of the Bybit error, if the request was rejected by Bybit.
+    pub fn kind(&self) -> Option<BybitErrorKind> {
+        match self {
+            Self::ErrorCode(code) => Some(code.kind),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug)]
 pub struct ErrorCode {
     pub code: i64,
+    pub kind: BybitErrorKind,
     pub msg: String,
     pub ext_code: String,
     pub ext_info: String,
@@ -33,6 +51,24 @@ impl From<reqwest::Error> for Error {
     }
 }
 
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Json(err)
+    }
+}
+
+impl From<ValidationError> for Error {
+    fn from(err: ValidationError) -> Self {
+        Self::Validation(err)
+    }
+}
+
+impl From<ApiKeyError> for Error {
+    fn from(err: ApiKeyError) -> Self {
+        Self::ApiKey(err)
+    }
+}
+
 impl From<ErrorCode> for Error {
     fn from(code: ErrorCode) -> Self {
         Self::ErrorCode(code)
@@ -49,6 +85,7 @@ impl<T> From<Response<T>> for ErrorCode {
     fn from(res: Response<T>) -> Self {
         ErrorCode {
             code: res.ret_code,
+            kind: res.ret_code.into(),
             msg: res.ret_msg,
             ext_code: res.ext_code,
             ext_info: res.ext_info,
@@ -0,0 +1,219 @@
+use serde::{Deserialize, Serialize};
+
+/// Classification of the `ret_code` returned by Bybit.
+/// Codes without a dedicated variant are kept as `Unknown`, so no information is lost.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(from = "i64", into = "i64")]
+pub enum BybitErrorKind {
+    /// Server timeout (10000)
+    ServerTimeout,
+    /// Invalid or missing parameters (10001)
+    ParamsError,
+    /// Request timestamp expired, the local clock may be out of sync (10002)
+    RequestExpired,
+    /// Invalid api key (10003)
+    InvalidApiKey,
+    /// Invalid signature (10004)
+    InvalidSign,
+    /// Permission denied for the api key (10005)
+    PermissionDenied,
+    /// Too many visits, the rate limit was exceeded (10006)
+    TooManyVisits,
+    /// Authentication failed (10007)
+    AuthenticationFailed,
+    /// The ip address does not match the api key (10010)
+    UnmatchedIp,
+    /// System busy, service unavailable (10016)
+    SystemBusy,
+    /// Request path not found (10017)
+    PathNotFound,
+    /// The ip rate limit was exceeded (10018)
+    IpRateLimited,
+    /// Order does not exist (20001)
+    OrderNotExists,
+    /// Insufficient wallet balance (30010)
+    InsufficientWalletBalance,
+    /// Insufficient available balance for the order cost (30031)
+    InsufficientOrderCost,
+    /// Order has already been filled or canceled (30032)
+    OrderFilledOrCanceled,
+    /// No order found (30034)
+    OrderNotFound,
+    /// Order is already being canceled (30037)
+    OrderAlreadyCanceling,
+    /// No position found (30041)
+    PositionNotFound,
+    /// Insufficient wallet balance (30042)
+    InsufficientBalance,
+    /// Reduce-only order rejected, it would increase the position (30063)
+    ReduceOnlyRejected,
+    /// The api key has expired (33004)
+    ApiKeyExpired,
+    /// Order not exists or too late to cancel (130010)
+    LinearOrderNotExists,
+    /// Insufficient available balance for the order cost (130021)
+    LinearInsufficientOrderCost,
+    /// Any other code
+    Unknown(i64),
+}
+
+impl BybitErrorKind {
+    /// Returns the classification of the given `ret_code`.
+    /// * `code` - The `ret_code` of the response.
+    pub fn from_code(code: i64) -> Self {
+        use BybitErrorKind::*;
+        match code {
+            10000 => ServerTimeout,
+            10001 => ParamsError,
+            10002 => RequestExpired,
+            10003 => InvalidApiKey,
+            10004 => InvalidSign,
+            10005 => PermissionDenied,
+            10006 => TooManyVisits,
+            10007 => AuthenticationFailed,
+            10010 => UnmatchedIp,
+            10016 => SystemBusy,
+            10017 => PathNotFound,
+            10018 => IpRateLimited,
+            20001 => OrderNotExists,
+            30010 => InsufficientWalletBalance,
+            30031 => InsufficientOrderCost,
+            30032 => OrderFilledOrCanceled,
+            30034 => OrderNotFound,
+            30037 => OrderAlreadyCanceling,
+            30041 => PositionNotFound,
+            30042 => InsufficientBalance,
+            30063 => ReduceOnlyRejected,
+            33004 => ApiKeyExpired,
+            130010 => LinearOrderNotExists,
+            130021 => LinearInsufficientOrderCost,
+            code => Unknown(code),
+        }
+    }
+
+    /// Returns the `ret_code` of this kind.
+    pub fn code(&self) -> i64 {
+        use BybitErrorKind::*;
+        match self {
+            ServerTimeout => 10000,
+            ParamsError => 10001,
+            RequestExpired => 10002,
+            InvalidApiKey => 10003,
+            InvalidSign => 10004,
+            PermissionDenied => 10005,
+            TooManyVisits => 10006,
+            AuthenticationFailed => 10007,
+            UnmatchedIp => 10010,
+            SystemBusy => 10016,
+            PathNotFound => 10017,
+            IpRateLimited => 10018,
+            OrderNotExists => 20001,
+            InsufficientWalletBalance => 30010,
+            InsufficientOrderCost => 30031,
+            OrderFilledOrCanceled => 30032,
+            OrderNotFound => 30034,
+            OrderAlreadyCanceling => 30037,
+            PositionNotFound => 30041,
+            InsufficientBalance => 30042,
+            ReduceOnlyRejected => 30063,
+            ApiKeyExpired => 33004,
+            LinearOrderNotExists => 130010,
+            LinearInsufficientOrderCost => 130021,
+            Unknown(code) => *code,
+        }
+    }
+
+    /// Returns `true` if the request may succeed when sent again.
+    pub fn is_retryable(&self) -> bool {
+        use BybitErrorKind::*;
+        matches!(self, ServerTimeout | RequestExpired | SystemBusy)
+    }
+
+    /// Returns `true` if the request was rejected because of the credentials.
+    pub fn is_auth_error(&self) -> bool {
+        use BybitErrorKind::*;
+        matches!(
+            self,
+            InvalidApiKey
+                | InvalidSign
+                | PermissionDenied
+                | AuthenticationFailed
+                | UnmatchedIp
+                | ApiKeyExpired
+        )
+    }
+
+    /// Returns `true` if the request was rejected because of a rate limit.
+    pub fn is_rate_limited(&self) -> bool {
+        use BybitErrorKind::*;
+        matches!(self, TooManyVisits | IpRateLimited)
+    }
+
+    /// Returns `true` if an order (or the change of an order) was rejected by the matching engine.
+    pub fn is_order_rejection(&self) -> bool {
+        use BybitErrorKind::*;
+        matches!(
+            self,
+            OrderNotExists
+                | InsufficientWalletBalance
+                | InsufficientOrderCost
+                | OrderFilledOrCanceled
+                | OrderNotFound
+                | OrderAlreadyCanceling
+                | PositionNotFound
+                | InsufficientBalance
+                | ReduceOnlyRejected
+                | LinearOrderNotExists
+                | LinearInsufficientOrderCost
+        )
+    }
+}
+
+impl From<i64> for BybitErrorKind {
+    fn from(code: i64) -> Self {
+        BybitErrorKind::from_code(code)
+    }
+}
+
+impl From<BybitErrorKind> for i64 {
+    fn from(kind: BybitErrorKind) -> Self {
+        kind.code()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for code in [0, 10002, 10006, 30031, 130021, 34036, -1] {
+            assert_eq!(BybitErrorKind::from_code(code).code(), code);
+        }
+        assert_eq!(
+            BybitErrorKind::from_code(34036),
+            BybitErrorKind::Unknown(34036)
+        );
+
+        let kind: BybitErrorKind = serde_json::from_str("10006").unwrap();
+        assert_eq!(kind, BybitErrorKind::TooManyVisits);
+        assert_eq!(serde_json::to_string(&kind).unwrap(), "10006");
+        let kind: BybitErrorKind = serde_json::from_str("99999").unwrap();
+        assert_eq!(serde_json::to_string(&kind).unwrap(), "99999");
+    }
+
+    #[test]
+    fn classification() {
+        assert!(BybitErrorKind::SystemBusy.is_retryable());
+        assert!(BybitErrorKind::InvalidSign.is_auth_error());
+        assert!(BybitErrorKind::TooManyVisits.is_rate_limited());
+        assert!(BybitErrorKind::InsufficientOrderCost.is_order_rejection());
+        assert!(!BybitErrorKind::InsufficientOrderCost.is_retryable());
+
+        let unknown = BybitErrorKind::Unknown(99999);
+        assert!(!unknown.is_retryable());
+        assert!(!unknown.is_auth_error());
+        assert!(!unknown.is_rate_limited());
+        assert!(!unknown.is_order_rejection());
+    }
+}
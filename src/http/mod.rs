@@ -1,10 +1,16 @@
 mod client;
 mod error;
+mod error_kind;
 mod query;
+mod rate_limit;
 mod response;
+mod retry;
 mod sign;
 pub use client::*;
 pub use error::*;
+pub use error_kind::*;
 pub use query::*;
+pub use rate_limit::*;
 pub use response::*;
+pub use retry::*;
 pub use sign::*;
@@ -5,7 +5,7 @@ use std::marker::PhantomData;
 
 pub trait Query: Serialize + Sized {}
 
-#[derive(Serialize)]
+#[derive(Serialize, Clone)]
 pub struct NoQuery {
     #[serde(skip)]
     data: PhantomData<()>,
@@ -16,6 +16,8 @@ pub struct SignedQuery<Q: Query> {
     #[serde(flatten)]
     query: Q,
     timestamp: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
     api_key: String,
     #[serde(rename = "sign")]
     signature: String,
@@ -24,9 +26,21 @@ pub struct SignedQuery<Q: Query> {
 impl<Q: Query> SignedQuery<Q> {
     /// Create a new signed query.
     pub fn new(query: Q, timestamp: i64, api_key: &str, secret: &str) -> Self {
+        SignedQuery::with_recv_window(query, timestamp, None, api_key, secret)
+    }
+
+    /// Create a new signed query that's only valid for `recv_window` milliseconds after `timestamp`.
+    pub fn with_recv_window(
+        query: Q,
+        timestamp: i64,
+        recv_window: Option<i64>,
+        api_key: &str,
+        secret: &str,
+    ) -> Self {
         let payload = Payload {
             query,
             timestamp,
+            recv_window,
             api_key: api_key.to_owned(),
         };
         Signed::new(payload, secret).into()
@@ -42,6 +56,8 @@ struct Payload<Q: Query> {
     #[serde(flatten)]
     query: Q,
     timestamp: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
     api_key: String,
 }
 
@@ -50,6 +66,7 @@ impl<Q: Query> From<Signed<Payload<Q>>> for SignedQuery<Q> {
         SignedQuery {
             query: signed.payload.query,
             timestamp: signed.payload.timestamp,
+            recv_window: signed.payload.recv_window,
             api_key: signed.payload.api_key,
             signature: signed.signature,
         }
@@ -92,6 +109,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn signed_query_recv_window() {
+        let query = TestQuery { item: "test" };
+        let signed = SignedQuery::with_recv_window(
+            query,
+            1626034022751,
+            Some(5000),
+            "test-api-key",
+            "test-secret",
+        );
+        let expected = crate::sign(
+            "api_key=test-api-key&item=test&recv_window=5000&timestamp=1626034022751",
+            "test-secret",
+        );
+        assert_eq!(signed.recv_window, Some(5000));
+        assert_eq!(signed.signature, expected);
+    }
+
     #[test]
     fn signed_no_query() {
         let query = NoQuery::new();
@@ -0,0 +1,221 @@
+use crate::Clock;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Mutex;
+use tokio::time::{self, Duration};
+
+/// Default length of the rate limit window (in milliseconds).
+pub const DEFAULT_RATE_LIMIT_WINDOW_MS: i64 = 60_000;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum EndpointFamily {
+    /// Public market data endpoints
+    Public,
+    /// Active and conditional order endpoints
+    Order,
+    /// Position endpoints
+    Position,
+    /// Wallet endpoints
+    Wallet,
+    /// Any other private endpoint
+    Other,
+}
+
+impl EndpointFamily {
+    /// Returns the endpoint family the given path belongs to.
+    /// * `path` - The path of the endpoint.
+    pub fn from_path(path: &str) -> Self {
+        let path = path.trim_start_matches('/');
+        if path.starts_with("v2/public/") || path.starts_with("public/") {
+            EndpointFamily::Public
+        } else if path.contains("/order") || path.contains("/stop-order") {
+            EndpointFamily::Order
+        } else if path.contains("/position") {
+            EndpointFamily::Position
+        } else if path.contains("/wallet") || path.contains("/exchange-order") {
+            EndpointFamily::Wallet
+        } else {
+            EndpointFamily::Other
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct RateLimit {
+    /// Remaining number of requests in the current window
+    pub remaining: i64,
+    /// Maximum number of requests per window
+    pub limit: i64,
+    /// Time (in milliseconds) at which the current window resets
+    pub reset_ms: i64,
+}
+
+/// Tracks the rate limit budget per endpoint family, as reported by Bybit.
+#[derive(Debug)]
+pub struct RateLimiter {
+    budgets: Mutex<HashMap<EndpointFamily, RateLimit>>,
+    window_ms: AtomicI64,
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        RateLimiter {
+            budgets: Default::default(),
+            window_ms: AtomicI64::new(DEFAULT_RATE_LIMIT_WINDOW_MS),
+        }
+    }
+}
+
+impl RateLimiter {
+    /// Create a new rate limiter without any known budget.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns the length of the rate limit window (in milliseconds) assumed when a window
+    /// resets, until Bybit reports the actual reset time.
+    pub fn window_ms(&self) -> i64 {
+        self.window_ms.load(Ordering::Relaxed)
+    }
+
+    /// Set the length of the rate limit window (in milliseconds).
+    /// * `window_ms` - The window length, see the rate limit documentation of the endpoint.
+    pub fn set_window_ms(&self, window_ms: i64) {
+        self.window_ms.store(window_ms, Ordering::Relaxed);
+    }
+
+    /// Returns the last known budget for the given endpoint family.
+    /// * `family` - The endpoint family.
+    pub fn budget(&self, family: EndpointFamily) -> Option<RateLimit> {
+        self.budgets.lock().unwrap().get(&family).copied()
+    }
+
+    /// Reserve a request for the given endpoint family,
+    /// waiting until the window resets if the budget is exhausted.
+    /// * `family` - The endpoint family.
+    /// * `clock` - The server clock, used to compare against the reset time reported by Bybit.
+    pub async fn acquire(&self, family: EndpointFamily, clock: &Clock) {
+        loop {
+            let wait = {
+                let mut budgets = self.budgets.lock().unwrap();
+                let budget = match budgets.get_mut(&family) {
+                    Some(budget) => budget,
+                    None => return,
+                };
+                let now = clock.timestamp_millis();
+                if budget.reset_ms <= now {
+                    // The actual budget will be known after the next response,
+                    // until then assume the window refilled.
+                    budget.remaining = budget.limit;
+                    budget.reset_ms = now + self.window_ms();
+                }
+                if budget.remaining > 0 {
+                    budget.remaining -= 1;
+                    return;
+                }
+                Duration::from_millis((budget.reset_ms - now) as u64)
+            };
+            time::sleep(wait).await;
+        }
+    }
+
+    /// Update the budget of the given endpoint family with the values reported by Bybit.
+    /// * `family` - The endpoint family.
+    /// * `remaining` - The `rate_limit_status` of the response.
+    /// * `limit` - The `rate_limit` of the response.
+    /// * `reset_ms` - The `rate_limit_reset_ms` of the response.
+    pub fn update(
+        &self,
+        family: EndpointFamily,
+        remaining: Option<i64>,
+        limit: Option<i64>,
+        reset_ms: Option<i64>,
+    ) {
+        if let (Some(remaining), Some(limit), Some(reset_ms)) = (remaining, limit, reset_ms) {
+            let budget = RateLimit {
+                remaining,
+                limit,
+                reset_ms,
+            };
+            self.budgets.lock().unwrap().insert(family, budget);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn endpoint_family() {
+        assert_eq!(
+            EndpointFamily::from_path("/v2/public/symbols"),
+            EndpointFamily::Public
+        );
+        assert_eq!(
+            EndpointFamily::from_path("/public/linear/kline"),
+            EndpointFamily::Public
+        );
+        assert_eq!(
+            EndpointFamily::from_path("/v2/private/order/create"),
+            EndpointFamily::Order
+        );
+        assert_eq!(
+            EndpointFamily::from_path("/private/linear/stop-order/create"),
+            EndpointFamily::Order
+        );
+        assert_eq!(
+            EndpointFamily::from_path("/v2/private/position/list"),
+            EndpointFamily::Position
+        );
+        assert_eq!(
+            EndpointFamily::from_path("/v2/private/wallet/balance"),
+            EndpointFamily::Wallet
+        );
+        assert_eq!(
+            EndpointFamily::from_path("/v2/private/account/api-key"),
+            EndpointFamily::Other
+        );
+    }
+
+    #[tokio::test]
+    async fn acquire() {
+        let clock = Clock::new();
+        let limiter = RateLimiter::new();
+        limiter.acquire(EndpointFamily::Order, &clock).await;
+        assert!(limiter.budget(EndpointFamily::Order).is_none());
+
+        let reset_ms = clock.timestamp_millis() + 100;
+        limiter.update(EndpointFamily::Order, Some(1), Some(100), Some(reset_ms));
+        limiter.acquire(EndpointFamily::Order, &clock).await;
+        assert_eq!(limiter.budget(EndpointFamily::Order).unwrap().remaining, 0);
+
+        limiter.acquire(EndpointFamily::Order, &clock).await;
+        assert!(clock.timestamp_millis() >= reset_ms);
+        let budget = limiter.budget(EndpointFamily::Order).unwrap();
+        assert_eq!(budget.remaining, 99);
+        assert!(budget.reset_ms >= reset_ms + DEFAULT_RATE_LIMIT_WINDOW_MS);
+    }
+
+    #[tokio::test]
+    async fn acquire_with_server_clock() {
+        // The local clock lags one minute behind the server clock,
+        // so the window reported by the server has already reset.
+        let clock = Clock::new();
+        clock.set_offset_ms(60_000);
+        let limiter = RateLimiter::new();
+        limiter.set_window_ms(5_000);
+
+        let reset_ms = clock.timestamp_millis() - 1_000;
+        limiter.update(EndpointFamily::Order, Some(0), Some(100), Some(reset_ms));
+        time::timeout(
+            Duration::from_secs(1),
+            limiter.acquire(EndpointFamily::Order, &clock),
+        )
+        .await
+        .unwrap();
+        let budget = limiter.budget(EndpointFamily::Order).unwrap();
+        assert_eq!(budget.remaining, 99);
+        assert!(budget.reset_ms <= clock.timestamp_millis() + 5_000);
+    }
+}
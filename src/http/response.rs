@@ -1,5 +1,5 @@
 use super::Result;
-use serde::Deserialize;
+use serde::{de::DeserializeOwned, de::IgnoredAny, Deserialize};
 
 #[derive(Deserialize)]
 pub struct Ignore {
@@ -29,10 +29,93 @@ impl<T> Response<T> {
         !self.is_ok()
     }
 
+    /// Returns the result of a successful response.
+    /// Any non-zero `ret_code` is an error, even if a result is present.
     pub fn result(self) -> Result<T> {
+        if self.is_err() {
+            return Err(self.into());
+        }
         if let Some(result) = self.result {
             return Ok(result);
         }
         Err(self.into())
     }
+
+    /// Returns the response itself if it is successful, ignoring its result.
+    pub fn check(self) -> Result<Self> {
+        if self.is_err() {
+            return Err(self.into());
+        }
+        Ok(self)
+    }
+}
+
+impl<T: DeserializeOwned> Response<T> {
+    /// Parse a response from its JSON body.
+    /// The result of an error response is dropped if it doesn't match `T`,
+    /// so the error itself is never lost.
+    /// * `body` - The body of the response.
+    pub fn from_slice(body: &[u8]) -> serde_json::Result<Self> {
+        let err = match serde_json::from_slice(body) {
+            Ok(response) => return Ok(response),
+            Err(err) => err,
+        };
+        match serde_json::from_slice::<Response<IgnoredAny>>(body) {
+            Ok(response) if response.is_err() => Ok(Response {
+                result: None,
+                ret_code: response.ret_code,
+                ret_msg: response.ret_msg,
+                ext_code: response.ext_code,
+                ext_info: response.ext_info,
+                time_now: response.time_now,
+                rate_limit_status: response.rate_limit_status,
+                rate_limit_reset_ms: response.rate_limit_reset_ms,
+                rate_limit: response.rate_limit,
+            }),
+            _ => Err(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::BybitErrorKind;
+
+    #[derive(Deserialize, Debug)]
+    struct Item {
+        id: i64,
+    }
+
+    #[test]
+    fn result_with_error_code() {
+        let body = br#"{"ret_code":30032,"ret_msg":"order has been finished or canceled","ext_code":"","ext_info":"","result":{"id":1},"time_now":"1577444332.192859"}"#;
+        let response: Response<Item> = Response::from_slice(body).unwrap();
+        assert_eq!(response.result.as_ref().map(|item| item.id), Some(1));
+        let err = response.result().unwrap_err();
+        assert_eq!(err.kind(), Some(BybitErrorKind::OrderFilledOrCanceled));
+    }
+
+    #[test]
+    fn mismatched_error_result() {
+        let body = br#"{"ret_code":10001,"ret_msg":"params error","ext_code":"","ext_info":"","result":{},"time_now":"1577444332.192859"}"#;
+        let response: Response<Item> = Response::from_slice(body).unwrap();
+        assert!(response.result.is_none());
+        assert_eq!(response.ret_code, 10001);
+
+        let body = br#"{"ret_code":0,"ret_msg":"OK","ext_code":"","ext_info":"","result":{},"time_now":"1577444332.192859"}"#;
+        assert!(Response::<Item>::from_slice(body).is_err());
+    }
+
+    #[test]
+    fn check() {
+        let body = br#"{"ret_code":0,"ret_msg":"OK","ext_code":"","ext_info":"","result":null,"time_now":"1577444332.192859"}"#;
+        let response: Response<Item> = Response::from_slice(body).unwrap();
+        assert!(response.check().is_ok());
+
+        let body = br#"{"ret_code":10006,"ret_msg":"too many visits!","ext_code":"","ext_info":"","result":null,"time_now":"1577444332.192859"}"#;
+        let response: Response<Item> = Response::from_slice(body).unwrap();
+        let err = response.check().unwrap_err();
+        assert!(err.kind().unwrap().is_rate_limited());
+    }
 }
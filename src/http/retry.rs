@@ -0,0 +1,109 @@
+use super::{BybitErrorKind, Error, Response, Result};
+use std::time::Duration;
+
+/// Policy describing which failed requests are retried and how long to wait in between.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    /// The maximum number of retries, `0` disables retrying.
+    pub max_retries: u32,
+    /// The delay before the first retry.
+    pub initial_backoff: Duration,
+    /// The upper bound of the delay between two retries.
+    pub max_backoff: Duration,
+    /// The factor the delay is multiplied with after every retry.
+    pub multiplier: f64,
+    /// The Bybit errors that are retried.
+    pub error_kinds: Vec<BybitErrorKind>,
+}
+
+impl RetryPolicy {
+    /// A policy that never retries.
+    pub fn none() -> Self {
+        RetryPolicy {
+            max_retries: 0,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the delay to wait before the given retry.
+    /// * `attempt` - The number of attempts made so far.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exp = self
+            .multiplier
+            .max(1.0)
+            .powi(attempt.saturating_sub(1).min(64) as i32);
+        let delay = (self.initial_backoff.as_secs_f64() * exp).min(self.max_backoff.as_secs_f64());
+        Duration::from_secs_f64(delay)
+    }
+
+    /// Returns `true` if the result of the given attempt should be retried.
+    /// * `attempt` - The number of attempts made so far.
+    /// * `result` - The result of the last attempt.
+    pub fn should_retry<T>(&self, attempt: u32, result: &Result<Response<T>>) -> bool {
+        if attempt > self.max_retries {
+            return false;
+        }
+        match result {
+            Ok(response) => self
+                .error_kinds
+                .contains(&BybitErrorKind::from_code(response.ret_code)),
+            Err(Error::Reqwest(err)) => {
+                err.is_timeout()
+                    || err.is_connect()
+                    || err.status().is_some_and(|status| status.is_server_error())
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(250),
+            max_backoff: Duration::from_secs(5),
+            multiplier: 2.0,
+            // The kinds for which `BybitErrorKind::is_retryable` holds.
+            error_kinds: vec![
+                BybitErrorKind::ServerTimeout,
+                BybitErrorKind::RequestExpired,
+                BybitErrorKind::SystemBusy,
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(kind: BybitErrorKind) -> Result<Response<()>> {
+        let json = format!(
+            r#"{{"ret_code":{},"ret_msg":"","ext_code":"","ext_info":"","result":null,"time_now":"1577444332.192859"}}"#,
+            kind.code()
+        );
+        Ok(serde_json::from_str(&json).unwrap())
+    }
+
+    #[test]
+    fn backoff() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.backoff(1), Duration::from_millis(250));
+        assert_eq!(policy.backoff(2), Duration::from_millis(500));
+        assert_eq!(policy.backoff(3), Duration::from_secs(1));
+        assert_eq!(policy.backoff(10), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn should_retry() {
+        let policy = RetryPolicy::default();
+        assert!(policy.error_kinds.iter().all(BybitErrorKind::is_retryable));
+        assert!(policy.should_retry(1, &response(BybitErrorKind::SystemBusy)));
+        assert!(policy.should_retry(3, &response(BybitErrorKind::RequestExpired)));
+        assert!(!policy.should_retry(4, &response(BybitErrorKind::RequestExpired)));
+        assert!(!policy.should_retry(1, &response(BybitErrorKind::Unknown(0))));
+        assert!(!policy.should_retry(1, &response(BybitErrorKind::InsufficientBalance)));
+        assert!(!RetryPolicy::none().should_retry(1, &response(BybitErrorKind::SystemBusy)));
+    }
+}
@@ -0,0 +1,201 @@
+use crate::{deserialize::string_or_number, Price, Qty};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// The interval of a kline.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Interval {
+    #[serde(rename = "1")]
+    Min1,
+    #[serde(rename = "3")]
+    Min3,
+    #[serde(rename = "5")]
+    Min5,
+    #[serde(rename = "15")]
+    Min15,
+    #[serde(rename = "30")]
+    Min30,
+    #[serde(rename = "60")]
+    Hour1,
+    #[serde(rename = "120")]
+    Hour2,
+    #[serde(rename = "240")]
+    Hour4,
+    #[serde(rename = "360")]
+    Hour6,
+    #[serde(rename = "720")]
+    Hour12,
+    #[serde(rename = "D")]
+    Day,
+    #[serde(rename = "W")]
+    Week,
+    #[serde(rename = "M")]
+    Month,
+}
+
+impl Interval {
+    /// All intervals, shortest first.
+    pub const ALL: [Interval; 13] = [
+        Interval::Min1,
+        Interval::Min3,
+        Interval::Min5,
+        Interval::Min15,
+        Interval::Min30,
+        Interval::Hour1,
+        Interval::Hour2,
+        Interval::Hour4,
+        Interval::Hour6,
+        Interval::Hour12,
+        Interval::Day,
+        Interval::Week,
+        Interval::Month,
+    ];
+
+    /// Returns the interval as used by the API, e.g. `15` or `D`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Interval::Min1 => "1",
+            Interval::Min3 => "3",
+            Interval::Min5 => "5",
+            Interval::Min15 => "15",
+            Interval::Min30 => "30",
+            Interval::Hour1 => "60",
+            Interval::Hour2 => "120",
+            Interval::Hour4 => "240",
+            Interval::Hour6 => "360",
+            Interval::Hour12 => "720",
+            Interval::Day => "D",
+            Interval::Week => "W",
+            Interval::Month => "M",
+        }
+    }
+
+    /// Returns the length of the interval in seconds.
+    /// Months are assumed to last 30 days.
+    pub fn seconds(&self) -> i64 {
+        const MINUTE: i64 = 60;
+        const DAY: i64 = 24 * 60 * MINUTE;
+        match self {
+            Interval::Day => DAY,
+            Interval::Week => 7 * DAY,
+            Interval::Month => 30 * DAY,
+            // The remaining intervals are expressed in minutes.
+            _ => self.as_str().parse::<i64>().unwrap_or(1) * MINUTE,
+        }
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Interval {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Interval::ALL
+            .iter()
+            .copied()
+            .find(|interval| interval.as_str() == s)
+            .ok_or_else(|| format!("Invalid kline interval: {}", s))
+    }
+}
+
+/// A kline (candle) of the traded price, of either an inverse or a linear contract.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Kline {
+    /// Symbol
+    pub symbol: String,
+    /// Interval
+    pub interval: Interval,
+    /// Start of the kline (in seconds)
+    pub open_time: i64,
+    /// Open price
+    pub open: Price,
+    /// Highest price
+    pub high: Price,
+    /// Lowest price
+    pub low: Price,
+    /// Close price
+    pub close: Price,
+    /// Trading volume
+    pub volume: Qty,
+    /// Trading turnover
+    #[serde(deserialize_with = "string_or_number")]
+    pub turnover: f64,
+}
+
+/// A kline of the mark price, the index price or the premium index.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PriceKline {
+    /// Symbol
+    pub symbol: String,
+    /// Interval
+    #[serde(rename = "period")]
+    pub interval: Interval,
+    /// Start of the kline (in seconds)
+    #[serde(alias = "start_at")]
+    pub open_time: i64,
+    /// Open value
+    pub open: Price,
+    /// Highest value
+    pub high: Price,
+    /// Lowest value
+    pub low: Price,
+    /// Close value
+    pub close: Price,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interval() {
+        for interval in Interval::ALL.iter() {
+            assert_eq!(interval.to_string().parse::<Interval>(), Ok(*interval));
+            let json = serde_json::to_string(interval).unwrap();
+            assert_eq!(json, format!("\"{}\"", interval));
+        }
+        assert!("2".parse::<Interval>().is_err());
+        assert_eq!(Interval::Hour4.seconds(), 4 * 60 * 60);
+        assert_eq!(Interval::Week.seconds(), 7 * 24 * 60 * 60);
+    }
+
+    #[test]
+    fn deserialize() {
+        let kline: Kline = serde_json::from_str(
+            r#"{"symbol":"BTCUSD","interval":"1","open_time":1581231300,"open":"10112.5",
+                "high":"10112.5","low":"10112","close":"10112","volume":"75981","turnover":"7.51463148"}"#,
+        )
+        .unwrap();
+        assert_eq!(kline.interval, Interval::Min1);
+        assert_eq!(kline.close, Price::from(10112));
+
+        let kline: Kline = serde_json::from_str(
+            r#"{"id":3866948,"symbol":"BTCUSDT","period":"1","interval":"1","start_at":1577836800,
+                "open_time":1577836800,"volume":1451.59,"open":7700,"high":999999,"low":0.5,
+                "close":6000,"turnover":2.4343353100000003}"#,
+        )
+        .unwrap();
+        assert_eq!(kline.volume, "1451.59".parse().unwrap());
+
+        let kline: PriceKline = serde_json::from_str(
+            r#"{"id":1,"symbol":"BTCUSD","period":"1","start_at":1582231260,"open":10106.09,
+                "high":10108.75,"low":10104.66,"close":10108.73}"#,
+        )
+        .unwrap();
+        assert_eq!(kline.open_time, 1582231260);
+
+        let kline: PriceKline = serde_json::from_str(
+            r#"{"symbol":"BTCUSD","period":"D","open_time":1582231260,"open":"0.000123",
+                "high":"0.0002","low":"-0.0001","close":"0.0001"}"#,
+        )
+        .unwrap();
+        assert_eq!(kline.interval, Interval::Day);
+        assert_eq!(kline.low, "-0.0001".parse().unwrap());
+    }
+}
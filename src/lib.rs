@@ -1,24 +1,52 @@
 mod announcement;
+mod api_key;
+mod clock;
+mod closed_pnl;
 mod contract;
+mod decimal;
 mod deserialize;
 mod error;
+mod execution;
 mod filter;
+mod funding;
 pub mod http;
+mod kline;
+mod market_data;
 mod order;
+mod order_book;
+mod position;
 pub mod rest;
+mod risk_limit;
 mod sign;
 mod symbol;
+mod symbol_registry;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 mod ticker;
 mod trade;
+mod validation;
 mod wallet;
 pub mod ws;
 pub use announcement::*;
+pub use api_key::*;
+pub use clock::*;
+pub use closed_pnl::*;
 pub use contract::*;
+pub use decimal::*;
 pub use error::*;
+pub use execution::*;
 pub use filter::*;
+pub use funding::*;
+pub use kline::*;
+pub use market_data::*;
 pub use order::*;
+pub use order_book::*;
+pub use position::*;
+pub use risk_limit::*;
 pub use sign::*;
 pub use symbol::*;
+pub use symbol_registry::*;
 pub use ticker::*;
 pub use trade::*;
+pub use validation::*;
 pub use wallet::*;
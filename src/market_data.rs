@@ -0,0 +1,87 @@
+use crate::{deserialize::string_or_number, order::Side, Qty};
+use serde::{Deserialize, Serialize};
+
+/// The period of advanced market data (open interest, long/short ratio).
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum DataPeriod {
+    #[serde(rename = "5min")]
+    Min5,
+    #[serde(rename = "15min")]
+    Min15,
+    #[serde(rename = "30min")]
+    Min30,
+    #[serde(rename = "1h")]
+    Hour1,
+    #[serde(rename = "4h")]
+    Hour4,
+    #[serde(rename = "1d")]
+    Day1,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct OpenInterest {
+    /// Symbol
+    pub symbol: String,
+    /// Open interest
+    pub open_interest: Qty,
+    /// Time (in seconds)
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct BigDeal {
+    /// Symbol
+    pub symbol: String,
+    /// Side of the taker
+    pub side: Side,
+    /// Time (in seconds)
+    pub timestamp: i64,
+    /// Value of the deal
+    #[serde(deserialize_with = "string_or_number")]
+    pub value: f64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct AccountRatio {
+    /// Symbol
+    pub symbol: String,
+    /// Share of accounts with a long position
+    #[serde(deserialize_with = "string_or_number")]
+    pub buy_ratio: f64,
+    /// Share of accounts with a short position
+    #[serde(deserialize_with = "string_or_number")]
+    pub sell_ratio: f64,
+    /// Time (in seconds)
+    pub timestamp: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let open_interest: OpenInterest = serde_json::from_str(
+            r#"{"open_interest":805604444,"timestamp":1645056000,"symbol":"BTCUSD"}"#,
+        )
+        .unwrap();
+        assert_eq!(open_interest.open_interest, Qty::from(805604444));
+
+        let deal: BigDeal = serde_json::from_str(
+            r#"{"symbol":"BTCUSD","side":"Sell","timestamp":1573731658,"value":1000000}"#,
+        )
+        .unwrap();
+        assert_eq!(deal.side, Side::Sell);
+
+        let ratio: AccountRatio = serde_json::from_str(
+            r#"{"symbol":"BTCUSD","buy_ratio":0.5477,"sell_ratio":0.4523,"timestamp":1584486000}"#,
+        )
+        .unwrap();
+        assert_eq!(ratio.buy_ratio, 0.5477);
+
+        assert_eq!(
+            serde_json::to_string(&DataPeriod::Min15).unwrap(),
+            "\"15min\""
+        );
+    }
+}
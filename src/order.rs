@@ -1,4 +1,5 @@
-use crate::deserialize::{optional_string_or_number, string_or_number};
+use crate::deserialize::{optional_decimal, optional_string_or_number, string_or_number};
+use crate::{Price, Qty};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
@@ -7,31 +8,35 @@ pub struct OrderId(String);
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
 pub struct OrderLinkId(String);
 
-#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Hash)]
 pub struct ExecId(String);
 
-#[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Default)]
 pub enum Side {
+    #[default]
     Buy,
     Sell,
 }
 
-#[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Default)]
 pub enum OrderType {
     Limit,
+    #[default]
     Market,
 }
 
-#[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Default)]
 pub enum TimeInForce {
+    #[default]
     GoodTillCancel,
     ImmediateOrCancel,
     FillOrKill,
     PostOnly,
 }
 
-#[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Default)]
 pub enum TriggerPrice {
+    #[default]
     #[serde(rename = "UNKNOWN")]
     Unknown,
     LastPrice,
@@ -166,8 +171,8 @@ pub struct LiquidatedOrder {
     pub id: LiquidatedOrderId,
     pub symbol: String,
     pub side: Side,
-    pub qty: f64,
-    pub price: f64,
+    pub qty: Qty,
+    pub price: Price,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -181,11 +186,9 @@ pub struct Order {
     #[serde(rename = "order_link_id")]
     pub link_id: OrderLinkId,
     /// Order price
-    #[serde(deserialize_with = "string_or_number")]
-    pub price: f64,
+    pub price: Price,
     /// Order quantity in USD.
-    #[serde(deserialize_with = "string_or_number")]
-    pub qty: f64,
+    pub qty: Qty,
     /// Symbol
     pub symbol: String,
     /// Side
@@ -198,8 +201,8 @@ pub struct Order {
     #[serde(deserialize_with = "string_or_number")]
     pub last_exec_time: f64,
     /// Last execution price
-    #[serde(deserialize_with = "optional_string_or_number", default)]
-    pub last_exec_price: Option<f64>,
+    #[serde(deserialize_with = "optional_decimal", default)]
+    pub last_exec_price: Option<Price>,
     /// Time in force
     pub time_in_force: TimeInForce,
     /// Trigger scenario for single action
@@ -207,14 +210,12 @@ pub struct Order {
     /// Trigger scenario for cancel operation
     pub cancel_type: Option<CancelType>,
     /// Number of unfilled contracts (from the order's size)
-    #[serde(deserialize_with = "string_or_number")]
-    pub leaves_qty: f64,
+    pub leaves_qty: Qty,
     /// The estimated value corresponding to the number of remaining orders.
     #[serde(deserialize_with = "optional_string_or_number", default)]
     pub leaves_value: Option<f64>,
     /// Cumulative qty of trading
-    #[serde(deserialize_with = "string_or_number")]
-    pub cum_exec_qty: f64,
+    pub cum_exec_qty: Qty,
     /// Cumulative value of trading
     #[serde(deserialize_with = "optional_string_or_number", default)]
     pub cum_exec_value: Option<f64>,
@@ -228,11 +229,9 @@ pub struct Order {
     /// Update time
     pub updated_at: String,
     /// Take profit price
-    #[serde(deserialize_with = "string_or_number")]
-    pub take_profit: f64,
+    pub take_profit: Price,
     /// Stop loss price
-    #[serde(deserialize_with = "string_or_number")]
-    pub stop_loss: f64,
+    pub stop_loss: Price,
     /// Take profit trigger price type
     pub tp_trigger_by: TriggerPrice,
     /// Stop loss trigger price type
@@ -250,11 +249,9 @@ pub struct LinearOrder {
     #[serde(rename = "order_link_id")]
     pub link_id: OrderLinkId,
     /// Order price
-    #[serde(deserialize_with = "string_or_number")]
-    pub price: f64,
+    pub price: Price,
     /// Order quantity in USD.
-    #[serde(deserialize_with = "string_or_number")]
-    pub qty: f64,
+    pub qty: Qty,
     /// Symbol
     pub symbol: String,
     /// Side
@@ -264,13 +261,12 @@ pub struct LinearOrder {
     /// Order type
     pub order_type: OrderType,
     /// Last execution price
-    #[serde(deserialize_with = "optional_string_or_number", default)]
-    pub last_exec_price: Option<f64>,
+    #[serde(deserialize_with = "optional_decimal", default)]
+    pub last_exec_price: Option<Price>,
     /// Time in force
     pub time_in_force: TimeInForce,
     /// Cumulative qty of trading
-    #[serde(deserialize_with = "string_or_number")]
-    pub cum_exec_qty: f64,
+    pub cum_exec_qty: Qty,
     /// Cumulative value of trading
     #[serde(deserialize_with = "optional_string_or_number", default)]
     pub cum_exec_value: Option<f64>,
@@ -286,11 +282,9 @@ pub struct LinearOrder {
     /// Update time
     pub updated_time: String,
     /// Take profit price
-    #[serde(deserialize_with = "string_or_number")]
-    pub take_profit: f64,
+    pub take_profit: Price,
     /// Stop loss price
-    #[serde(deserialize_with = "string_or_number")]
-    pub stop_loss: f64,
+    pub stop_loss: Price,
     /// Take profit trigger price type
     pub tp_trigger_by: TriggerPrice,
     /// Stop loss trigger price type
@@ -310,56 +304,67 @@ pub struct ConditionalOrder {
     #[serde(rename = "order_link_id")]
     pub link_id: OrderLinkId,
     /// Order price
-    #[serde(deserialize_with = "string_or_number")]
-    pub price: f64,
+    pub price: Price,
     /// Order quantity in USD.
-    #[serde(deserialize_with = "string_or_number")]
-    pub qty: f64,
+    pub qty: Qty,
     /// Symbol
     pub symbol: String,
     /// Side
     pub side: Side,
     /// Order status
-    pub order_status: OrderStatus,
+    #[serde(alias = "stop_order_status")]
+    pub order_status: StopOrderStatus,
     /// Order type
     pub order_type: OrderType,
     /// Last execution price
-    #[serde(deserialize_with = "optional_string_or_number", default)]
-    pub last_exec_price: Option<f64>,
+    #[serde(deserialize_with = "optional_decimal", default)]
+    pub last_exec_price: Option<Price>,
     /// Time in force
     pub time_in_force: TimeInForce,
-    /// Reduce only
+    /// Reduce only (linear only)
+    #[serde(default)]
     pub reduce_only: bool,
     /// Can only reduce your position
+    #[serde(default)]
     pub close_on_trigger: bool,
     /// Creation time
     pub created_time: Option<String>,
     /// Update time
     pub updated_time: Option<String>,
+    /// Created at (for inverse)
+    pub created_at: Option<String>,
     /// Update at (for inverse)
     pub updated_at: Option<String>,
     /// Take profit price
-    #[serde(deserialize_with = "string_or_number")]
-    pub take_profit: f64,
+    #[serde(default)]
+    pub take_profit: Price,
     /// Stop loss price
-    #[serde(deserialize_with = "string_or_number")]
-    pub stop_loss: f64,
+    #[serde(default)]
+    pub stop_loss: Price,
     /// Take profit trigger price type
+    #[serde(default)]
     pub tp_trigger_by: TriggerPrice,
     /// Stop loss trigger price type
+    #[serde(default)]
     pub sl_trigger_by: TriggerPrice,
     /// Position idx
+    #[serde(default)]
     pub position_idx: i64,
     /// Order trigger price type
     pub trigger_by: TriggerPrice,
     /// Market price at placing order
-    pub base_price: String,
+    pub base_price: Price,
     /// Remark
     pub remark: Option<String>,
     /// Reject reason (maybe change to enum later? bad api docs)
     pub reject_reason: Option<String>,
-    /// Trigger price
-    pub stop_px: Option<String>,
+    /// Trigger price, reported as `trigger_price` for linear contracts
+    #[serde(
+        alias = "trigger_price",
+        deserialize_with = "optional_decimal",
+        default
+    )]
+    pub stop_px: Option<Price>,
 }
 
 impl std::fmt::Display for OrderId {
@@ -385,21 +390,3 @@ impl std::fmt::Display for LiquidatedOrderId {
         write!(f, "{}", self.0)
     }
 }
-
-impl Default for Side {
-    fn default() -> Self {
-        Side::Buy
-    }
-}
-
-impl Default for OrderType {
-    fn default() -> Self {
-        OrderType::Market
-    }
-}
-
-impl Default for TimeInForce {
-    fn default() -> Self {
-        TimeInForce::GoodTillCancel
-    }
-}
@@ -0,0 +1,82 @@
+use crate::{order::Side, Price, Qty};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct OrderBookEntry {
+    /// Symbol
+    pub symbol: String,
+    /// Price of the level
+    pub price: Price,
+    /// Total size at the level
+    pub size: Qty,
+    /// `Buy` for bids, `Sell` for asks
+    pub side: Side,
+}
+
+/// A snapshot of the order book of a symbol, as returned by `rest::FetchOrderBook`.
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(from = "Vec<OrderBookEntry>")]
+pub struct OrderBookL2 {
+    /// Bids, best (highest) price first
+    pub bids: Vec<OrderBookEntry>,
+    /// Asks, best (lowest) price first
+    pub asks: Vec<OrderBookEntry>,
+}
+
+impl OrderBookL2 {
+    /// Returns the best bid.
+    pub fn best_bid(&self) -> Option<&OrderBookEntry> {
+        self.bids.first()
+    }
+
+    /// Returns the best ask.
+    pub fn best_ask(&self) -> Option<&OrderBookEntry> {
+        self.asks.first()
+    }
+
+    /// Returns the price halfway between the best bid and the best ask.
+    pub fn mid(&self) -> Option<Price> {
+        let bid = self.best_bid()?.price;
+        let ask = self.best_ask()?.price;
+        Some((bid + ask) / rust_decimal::Decimal::TWO)
+    }
+
+    /// Returns the difference between the best ask and the best bid.
+    pub fn spread(&self) -> Option<Price> {
+        Some(self.best_ask()?.price - self.best_bid()?.price)
+    }
+}
+
+impl From<Vec<OrderBookEntry>> for OrderBookL2 {
+    fn from(entries: Vec<OrderBookEntry>) -> Self {
+        let (mut bids, mut asks): (Vec<_>, Vec<_>) = entries
+            .into_iter()
+            .partition(|entry| entry.side == Side::Buy);
+        bids.sort_by_key(|entry| std::cmp::Reverse(entry.price));
+        asks.sort_by_key(|entry| entry.price);
+        OrderBookL2 { bids, asks }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let book: OrderBookL2 = serde_json::from_str(
+            r#"[
+                {"symbol": "BTCUSD", "price": "9487", "size": 336241, "side": "Buy"},
+                {"symbol": "BTCUSD", "price": "9487.5", "size": 522147, "side": "Sell"},
+                {"symbol": "BTCUSD", "price": "9488", "size": 1200, "side": "Sell"},
+                {"symbol": "BTCUSD", "price": "9486.5", "size": 4000, "side": "Buy"}
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(book.bids.len(), 2);
+        assert_eq!(book.best_bid().unwrap().price, Price::from(9487));
+        assert_eq!(book.best_ask().unwrap().size, Qty::from(522147));
+        assert_eq!(book.spread(), Some("0.5".parse().unwrap()));
+        assert_eq!(book.mid(), Some("9487.25".parse().unwrap()));
+    }
+}
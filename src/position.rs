@@ -0,0 +1,144 @@
+use crate::deserialize::{optional_decimal, optional_string_or_number, string_or_number};
+use crate::{order::Side, Price, Qty, TriggerPrice, UserId};
+use serde::{de, Deserialize, Deserializer, Serialize};
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq)]
+pub enum PositionStatus {
+    Normal,
+    Liq,
+    Adl,
+}
+
+/// A position, as returned by `rest::ListPositions` and the `Position` ws channel.
+/// Fields only reported by one of them are optional.
+#[derive(Deserialize, Debug, Clone)]
+pub struct Position {
+    /// User id
+    pub user_id: UserId,
+    /// Symbol
+    pub symbol: String,
+    /// Side, `None` if there is no position
+    #[serde(deserialize_with = "position_side")]
+    pub side: Option<Side>,
+    /// Position size
+    pub size: Qty,
+    /// Position value
+    #[serde(deserialize_with = "string_or_number")]
+    pub position_value: f64,
+    /// Average entry price
+    pub entry_price: Price,
+    /// Liquidation price
+    pub liq_price: Price,
+    /// Bankruptcy price
+    pub bust_price: Price,
+    /// Leverage
+    #[serde(deserialize_with = "string_or_number")]
+    pub leverage: f64,
+    /// `true` for isolated margin, `false` for cross margin
+    pub is_isolated: Option<bool>,
+    /// Whether margin is added automatically (0 or 1)
+    pub auto_add_margin: i64,
+    /// Order margin
+    #[serde(deserialize_with = "optional_string_or_number", default)]
+    pub order_margin: Option<f64>,
+    /// Position margin
+    #[serde(deserialize_with = "string_or_number")]
+    pub position_margin: f64,
+    /// Available balance
+    #[serde(deserialize_with = "optional_string_or_number", default)]
+    pub available_balance: Option<f64>,
+    /// Take profit price, 0 if not set
+    pub take_profit: Price,
+    /// Take profit trigger price type
+    pub tp_trigger_by: Option<TriggerPrice>,
+    /// Stop loss price, 0 if not set
+    pub stop_loss: Price,
+    /// Stop loss trigger price type
+    pub sl_trigger_by: Option<TriggerPrice>,
+    /// Realised PNL of the day
+    #[serde(deserialize_with = "string_or_number")]
+    pub realised_pnl: f64,
+    /// Unrealised PNL
+    #[serde(deserialize_with = "optional_string_or_number", default)]
+    pub unrealised_pnl: Option<f64>,
+    /// Trailing stop (distance from the market price), 0 if not set
+    pub trailing_stop: Price,
+    /// Trailing stop activation price
+    #[serde(deserialize_with = "optional_decimal", default)]
+    pub trailing_active: Option<Price>,
+    /// Wallet balance
+    #[serde(deserialize_with = "optional_string_or_number", default)]
+    pub wallet_balance: Option<f64>,
+    /// Risk limit id
+    pub risk_id: i64,
+    /// Position closing fee occupied
+    #[serde(deserialize_with = "string_or_number")]
+    pub occ_closing_fee: f64,
+    /// Pre-occupied funding fee
+    #[serde(deserialize_with = "optional_string_or_number", default)]
+    pub occ_funding_fee: Option<f64>,
+    /// Accumulated realised PNL
+    #[serde(deserialize_with = "string_or_number")]
+    pub cum_realised_pnl: f64,
+    /// Position status
+    pub position_status: Option<PositionStatus>,
+    /// Position sequence
+    pub position_seq: Option<i64>,
+    /// Position index (linear contracts only), 1 for the buy side and 2 for the sell side
+    pub position_idx: Option<i64>,
+    /// Quantity that can be closed (linear contracts only)
+    #[serde(deserialize_with = "optional_decimal", default)]
+    pub free_qty: Option<Qty>,
+}
+
+/// Deserializes the side of a position, where `None` means there is no position.
+fn position_side<'de, D>(deserializer: D) -> Result<Option<Side>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match String::deserialize(deserializer)?.as_str() {
+        "Buy" => Ok(Some(Side::Buy)),
+        "Sell" => Ok(Some(Side::Sell)),
+        "None" | "" => Ok(None),
+        side => Err(de::Error::unknown_variant(side, &["Buy", "Sell", "None"])),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let position: Position = serde_json::from_str(
+            r#"{"id":27913,"user_id":1,"risk_id":1,"symbol":"BTCUSD","side":"Buy","size":5,
+                "position_value":"0.0006947","entry_price":"7197.35137469","is_isolated":true,
+                "auto_add_margin":0,"leverage":"1","effective_leverage":"1",
+                "position_margin":"0.0006947","liq_price":"3608","bust_price":"3599",
+                "occ_closing_fee":"0.00000105","occ_funding_fee":"0","take_profit":"0",
+                "stop_loss":"0","trailing_stop":"0","position_status":"Normal",
+                "deleverage_indicator":4,"order_margin":"0.00029477",
+                "wallet_balance":"0.03000227","realised_pnl":"-0.00000126","unrealised_pnl":0,
+                "cum_realised_pnl":"-0.00001306","cross_seq":444081383,"position_seq":287141589,
+                "created_at":"2019-10-19T17:04:55Z","updated_at":"2019-12-12T03:16:45Z"}"#,
+        )
+        .unwrap();
+        assert_eq!(position.side, Some(Side::Buy));
+        assert_eq!(position.size, Qty::from(5));
+        assert_eq!(position.position_status, Some(PositionStatus::Normal));
+
+        let position: Position = serde_json::from_str(
+            r#"{"user_id":100004,"symbol":"BTCUSDT","side":"None","size":0.5,
+                "position_value":0,"entry_price":0,"liq_price":1,"bust_price":100,"leverage":100,
+                "is_isolated":true,"auto_add_margin":0,"position_margin":0,"occ_closing_fee":0,
+                "realised_pnl":0,"cum_realised_pnl":0,"free_qty":30,"tp_sl_mode":"Full",
+                "unrealised_pnl":0,"deleverage_indicator":0,"risk_id":1,"stop_loss":0,
+                "take_profit":0,"trailing_stop":0,"position_idx":1,"mode":"BothSide"}"#,
+        )
+        .unwrap();
+        assert_eq!(position.side, None);
+        assert_eq!(position.size, "0.5".parse().unwrap());
+        assert_eq!(position.position_idx, Some(1));
+        assert!(position.position_status.is_none());
+    }
+}
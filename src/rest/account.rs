@@ -0,0 +1,77 @@
+use crate::{
+    http::{Client, NoQuery, Query, Response, Result},
+    ApiKeyError, ApiKeyInfo, ApiKeyPermission, LcpInfo,
+};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait FetchApiKeyInfo {
+    /// Fetch the information of the API keys of the account.
+    async fn fetch_api_key_info(&self) -> Result<Vec<ApiKeyInfo>>;
+    /// Fetch the information of the API key of this client and check that it is not read only
+    /// and has exactly the given permissions.
+    /// * `permissions` - The expected permissions.
+    async fn verify_api_key(&self, permissions: &[ApiKeyPermission]) -> Result<ApiKeyInfo>;
+}
+
+#[async_trait]
+pub trait FetchLcpInfo {
+    /// Fetch the liquidity contribution points of the last days, newest first.
+    /// * `symbol` - The symbol to fetch the liquidity contribution points for.
+    async fn fetch_lcp_info(&self, symbol: &str) -> Result<Vec<LcpInfo>>;
+}
+
+#[async_trait]
+impl FetchApiKeyInfo for Client {
+    async fn fetch_api_key_info(&self) -> Result<Vec<ApiKeyInfo>> {
+        let query = NoQuery::new();
+        let response: Response<Vec<ApiKeyInfo>> = self
+            .signed_get("/v2/private/account/api-key", query)
+            .await?;
+        response.result()
+    }
+
+    async fn verify_api_key(&self, permissions: &[ApiKeyPermission]) -> Result<ApiKeyInfo> {
+        let info = self
+            .fetch_api_key_info()
+            .await?
+            .into_iter()
+            .find(|info| info.api_key == self.api_key())
+            .ok_or_else(|| ApiKeyError::NotFound(self.api_key().to_owned()))?;
+        info.check_permissions(permissions)?;
+        Ok(info)
+    }
+}
+
+#[async_trait]
+impl FetchLcpInfo for Client {
+    async fn fetch_lcp_info(&self, symbol: &str) -> Result<Vec<LcpInfo>> {
+        let query = request::LcpInfo {
+            symbol: symbol.to_owned(),
+        };
+        let response: Response<response::LcpInfo> =
+            self.signed_get("/v2/private/account/lcp", query).await?;
+        response.result().map(|result| result.lcp_list)
+    }
+}
+
+mod request {
+    use super::*;
+    use serde::Serialize;
+
+    #[derive(Serialize, Clone)]
+    pub struct LcpInfo {
+        pub symbol: String,
+    }
+
+    impl Query for LcpInfo {}
+}
+
+mod response {
+    use serde::Deserialize;
+
+    #[derive(Deserialize, Debug)]
+    pub struct LcpInfo {
+        pub lcp_list: Vec<crate::LcpInfo>,
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{
+    http::{Client, Query, Response, Result},
+    ClosedPnl, ExecType,
+};
+use async_trait::async_trait;
+use serde::Serialize;
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct FetchClosedPnlOptions {
+    pub symbol: String,
+    /// Records after this time (in seconds)
+    pub start_time: Option<i64>,
+    /// Records before this time (in seconds)
+    pub end_time: Option<i64>,
+    /// Only the records of this execution type
+    pub exec_type: Option<ExecType>,
+    /// Page number, starting at 1
+    pub page: Option<i64>,
+    /// Number of records per page, up to 50
+    pub limit: Option<i64>,
+}
+
+impl FetchClosedPnlOptions {
+    /// Create the options for fetching the closed profit and loss of a symbol.
+    /// * `symbol` - The symbol to fetch the closed profit and loss for.
+    pub fn new(symbol: &str) -> Self {
+        FetchClosedPnlOptions {
+            symbol: symbol.to_owned(),
+            ..Default::default()
+        }
+    }
+}
+
+#[async_trait]
+pub trait FetchClosedPnl {
+    /// Fetch a page of the closed profit and loss records of a symbol, newest first.
+    /// The endpoint is chosen by the contract type of the symbol.
+    /// * `options` - The options for fetching the records.
+    async fn fetch_closed_pnl(&self, options: FetchClosedPnlOptions) -> Result<Vec<ClosedPnl>>;
+}
+
+impl Query for FetchClosedPnlOptions {}
+
+#[async_trait]
+impl FetchClosedPnl for Client {
+    async fn fetch_closed_pnl(&self, options: FetchClosedPnlOptions) -> Result<Vec<ClosedPnl>> {
+        let path = if self.contract_type(&options.symbol).is_linear() {
+            "/private/linear/trade/closed-pnl/list"
+        } else {
+            "/v2/private/trade/closed-pnl/list"
+        };
+        let response: Response<response::ClosedPnl> = self.signed_get(path, options).await?;
+        response
+            .result()
+            .map(|result| result.data.unwrap_or_default())
+    }
+}
+
+mod response {
+    use serde::Deserialize;
+
+    /// The list is `null` when there are no records.
+    #[derive(Deserialize, Debug)]
+    pub struct ClosedPnl {
+        pub data: Option<Vec<crate::ClosedPnl>>,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query() {
+        let options = FetchClosedPnlOptions {
+            exec_type: Some(ExecType::Trade),
+            page: Some(2),
+            ..FetchClosedPnlOptions::new("BTCUSD")
+        };
+        assert_eq!(
+            serde_urlencoded::to_string(&options).unwrap(),
+            "symbol=BTCUSD&exec_type=Trade&page=2"
+        );
+    }
+}
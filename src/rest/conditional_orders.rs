@@ -0,0 +1,341 @@
+use super::orders::existing_order;
+use crate::{
+    http::{Client, Query, Response, Result},
+    order::*,
+    Price, Qty,
+};
+use async_trait::async_trait;
+use serde::Serialize;
+
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct ListConditionalOrdersFilter {
+    pub symbol: String,
+    pub stop_order_status: Option<StopOrderStatus>,
+    /// Page direction, `prev` or `next` (inverse contracts only)
+    pub direction: Option<String>,
+    pub limit: Option<i64>,
+    /// Page cursor (inverse contracts only)
+    pub cursor: Option<String>,
+    /// Page number, starting at 1 (linear contracts only)
+    pub page: Option<i64>,
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum ConditionalOrderId {
+    StopOrderId(OrderId),
+    OrderLinkId(OrderLinkId),
+}
+
+impl ConditionalOrderId {
+    pub fn stop_order_id(&self) -> Option<OrderId> {
+        match self {
+            ConditionalOrderId::StopOrderId(stop_order_id) => Some(stop_order_id.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn order_link_id(&self) -> Option<OrderLinkId> {
+        match self {
+            ConditionalOrderId::OrderLinkId(order_link_id) => Some(order_link_id.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl From<OrderId> for ConditionalOrderId {
+    fn from(stop_order_id: OrderId) -> Self {
+        ConditionalOrderId::StopOrderId(stop_order_id)
+    }
+}
+
+impl From<OrderLinkId> for ConditionalOrderId {
+    fn from(order_link_id: OrderLinkId) -> Self {
+        ConditionalOrderId::OrderLinkId(order_link_id)
+    }
+}
+
+/// The fields of an untriggered conditional order to amend, `None` keeps the current value.
+#[derive(Debug, Default)]
+pub struct UpdateConditionalOrderData {
+    pub symbol: String,
+    pub qty: Option<Qty>,
+    pub price: Option<Price>,
+    pub trigger_price: Option<Price>,
+}
+
+#[async_trait]
+pub trait ListConditionalOrders {
+    /// List the conditional orders of a symbol, newest first.
+    /// The endpoint is chosen by the contract type of the symbol.
+    /// * `filter` - The filter of the orders.
+    async fn list_conditional_orders(
+        &self,
+        filter: ListConditionalOrdersFilter,
+    ) -> Result<Vec<ConditionalOrder>>;
+}
+
+#[async_trait]
+pub trait UpdateConditionalOrders {
+    /// Amend an untriggered conditional order.
+    /// The endpoint is chosen by the contract type of the symbol.
+    /// Returns the id of the amended order.
+    /// * `conditional_order_id` - The id of the order.
+    /// * `data` - The fields to amend.
+    async fn update_conditional_order(
+        &self,
+        conditional_order_id: ConditionalOrderId,
+        data: UpdateConditionalOrderData,
+    ) -> Result<OrderId>;
+}
+
+#[async_trait]
+pub trait CancelConditionalOrders {
+    /// Cancel an untriggered conditional order.
+    /// The endpoint is chosen by the contract type of the symbol.
+    /// * `conditional_order_id` - The id of the order.
+    /// * `symbol` - The symbol of the order.
+    async fn cancel_conditional_order(
+        &self,
+        conditional_order_id: ConditionalOrderId,
+        symbol: &str,
+    ) -> Result<()>;
+    /// Cancel all untriggered conditional orders of a symbol.
+    /// The endpoint is chosen by the contract type of the symbol.
+    /// Returns the ids of the cancelled orders.
+    /// * `symbol` - The symbol of the orders.
+    async fn cancel_all_conditional_orders(&self, symbol: &str) -> Result<Vec<OrderId>>;
+}
+
+#[async_trait]
+pub trait QueryConditionalOrder {
+    /// Query a conditional order in real time.
+    /// The endpoint is chosen by the contract type of the symbol.
+    /// Returns `None` if Bybit doesn't know the order.
+    /// * `conditional_order_id` - The id of the order.
+    /// * `symbol` - The symbol of the order.
+    async fn query_conditional_order(
+        &self,
+        conditional_order_id: ConditionalOrderId,
+        symbol: &str,
+    ) -> Result<Option<ConditionalOrder>>;
+}
+
+#[async_trait]
+impl ListConditionalOrders for Client {
+    async fn list_conditional_orders(
+        &self,
+        filter: ListConditionalOrdersFilter,
+    ) -> Result<Vec<ConditionalOrder>> {
+        let path = if self.contract_type(&filter.symbol).is_linear() {
+            "/private/linear/stop-order/list"
+        } else {
+            "/v2/private/stop-order/list"
+        };
+        let query = request::ListConditionalOrders { filter };
+        let response: Response<response::ListConditionalOrders> =
+            self.signed_get(path, query).await?;
+        response.result().map(|res| res.orders.unwrap_or_default())
+    }
+}
+
+#[async_trait]
+impl UpdateConditionalOrders for Client {
+    async fn update_conditional_order(
+        &self,
+        conditional_order_id: ConditionalOrderId,
+        data: UpdateConditionalOrderData,
+    ) -> Result<OrderId> {
+        let path = if self.contract_type(&data.symbol).is_linear() {
+            "/private/linear/stop-order/replace"
+        } else {
+            "/v2/private/stop-order/replace"
+        };
+        let query = request::UpdateConditionalOrder {
+            conditional_order_id,
+            symbol: data.symbol,
+            qty: data.qty,
+            price: data.price,
+            trigger_price: data.trigger_price,
+        };
+        let response: Response<response::ConditionalOrderId> =
+            self.signed_post(path, query).await?;
+        response.result().map(|res| res.stop_order_id)
+    }
+}
+
+#[async_trait]
+impl CancelConditionalOrders for Client {
+    async fn cancel_conditional_order(
+        &self,
+        conditional_order_id: ConditionalOrderId,
+        symbol: &str,
+    ) -> Result<()> {
+        let path = if self.contract_type(symbol).is_linear() {
+            "/private/linear/stop-order/cancel"
+        } else {
+            "/v2/private/stop-order/cancel"
+        };
+        let query = request::CancelConditionalOrder {
+            conditional_order_id,
+            symbol: symbol.to_owned(),
+        };
+        let response: Response<response::ConditionalOrderId> =
+            self.signed_post(path, query).await?;
+        response.result().map(|_| ())
+    }
+
+    async fn cancel_all_conditional_orders(&self, symbol: &str) -> Result<Vec<OrderId>> {
+        let query = request::CancelAllConditionalOrders {
+            symbol: symbol.to_owned(),
+        };
+        if self.contract_type(symbol).is_linear() {
+            let response: Response<Vec<OrderId>> = self
+                .signed_post("/private/linear/stop-order/cancel-all", query)
+                .await?;
+            response.result()
+        } else {
+            let response: Response<Vec<response::CancelledOrder>> = self
+                .signed_post("/v2/private/stop-order/cancelAll", query)
+                .await?;
+            response
+                .result()
+                .map(|res| res.into_iter().map(|order| order.cl_ord_id).collect())
+        }
+    }
+}
+
+#[async_trait]
+impl QueryConditionalOrder for Client {
+    async fn query_conditional_order(
+        &self,
+        conditional_order_id: ConditionalOrderId,
+        symbol: &str,
+    ) -> Result<Option<ConditionalOrder>> {
+        let path = if self.contract_type(symbol).is_linear() {
+            "/private/linear/stop-order/search"
+        } else {
+            "/v2/private/stop-order"
+        };
+        let query = request::QueryConditionalOrder {
+            conditional_order_id,
+            symbol: symbol.to_owned(),
+        };
+        let response: Response<ConditionalOrder> = self.signed_get(path, query).await?;
+        existing_order(response)
+    }
+}
+
+mod request {
+    use super::*;
+
+    #[derive(Serialize, Clone)]
+    #[serde(transparent)]
+    pub struct ListConditionalOrders {
+        pub filter: ListConditionalOrdersFilter,
+    }
+
+    #[derive(Serialize, Clone)]
+    pub struct UpdateConditionalOrder {
+        #[serde(flatten)]
+        pub conditional_order_id: ConditionalOrderId,
+        pub symbol: String,
+        #[serde(rename = "p_r_qty", skip_serializing_if = "Option::is_none")]
+        pub qty: Option<Qty>,
+        #[serde(rename = "p_r_price", skip_serializing_if = "Option::is_none")]
+        pub price: Option<Price>,
+        #[serde(rename = "p_r_trigger_price", skip_serializing_if = "Option::is_none")]
+        pub trigger_price: Option<Price>,
+    }
+
+    #[derive(Serialize, Clone)]
+    pub struct CancelConditionalOrder {
+        #[serde(flatten)]
+        pub conditional_order_id: ConditionalOrderId,
+        pub symbol: String,
+    }
+
+    #[derive(Serialize, Clone)]
+    pub struct CancelAllConditionalOrders {
+        pub symbol: String,
+    }
+
+    #[derive(Serialize, Clone)]
+    pub struct QueryConditionalOrder {
+        #[serde(flatten)]
+        pub conditional_order_id: ConditionalOrderId,
+        pub symbol: String,
+    }
+
+    impl Query for ListConditionalOrders {}
+    impl Query for UpdateConditionalOrder {}
+    impl Query for CancelConditionalOrder {}
+    impl Query for CancelAllConditionalOrders {}
+    impl Query for QueryConditionalOrder {}
+}
+
+mod response {
+    use super::{ConditionalOrder, OrderId};
+    use serde::Deserialize;
+
+    /// The list is `null` when a linear symbol has no conditional orders.
+    #[derive(Deserialize)]
+    pub struct ListConditionalOrders {
+        #[serde(rename = "data")]
+        pub orders: Option<Vec<ConditionalOrder>>,
+    }
+
+    #[derive(Deserialize)]
+    pub struct ConditionalOrderId {
+        pub stop_order_id: OrderId,
+    }
+
+    #[derive(Deserialize)]
+    pub struct CancelledOrder {
+        #[serde(rename = "clOrdID")]
+        pub cl_ord_id: OrderId,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn list_response() {
+        let response: Response<response::ListConditionalOrders> =
+            Response::from_slice(include_bytes!("fixtures/stop_order_list.json")).unwrap();
+        let orders = response.result().unwrap().orders.unwrap();
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0].order_status, StopOrderStatus::Untriggered);
+        assert_eq!(orders[0].stop_px, Some(Price::from(7500)));
+    }
+
+    #[test]
+    fn list_response_without_orders() {
+        let body = br#"{"ret_code":0,"ret_msg":"OK","ext_code":"","ext_info":"","result":{"current_page":1,"last_page":0,"data":null},"time_now":"1604653633.173848"}"#;
+        let response: Response<response::ListConditionalOrders> =
+            Response::from_slice(body).unwrap();
+        assert!(response.result().unwrap().orders.is_none());
+    }
+
+    #[test]
+    fn cancel_all_responses() {
+        // Inverse returns the cancelled orders, with the order id as `clOrdID`.
+        let body = br#"{"ret_code":0,"ret_msg":"OK","ext_code":"","ext_info":"","result":[{"clOrdID":"89a38056-80f1-45b2-89d3-4d8e3a203a79","user_id":1,"symbol":"BTCUSD","side":"Buy","order_type":"Limit","price":"7693.5","qty":1,"time_in_force":"GoodTillCancel","create_type":"CreateByUser","cancel_type":"CancelByUser","order_status":"","leaves_qty":1,"leaves_value":"0","created_at":"2020-01-17T13:47:27.000Z","updated_at":"2020-01-17T13:47:27.000Z","cross_status":"Deactivated","cross_seq":1,"stop_order_type":"Stop","trigger_by":"LastPrice","base_price":"7864","expected_direction":"Rising"}],"time_now":"1579270047.431131"}"#;
+        let response: Response<Vec<response::CancelledOrder>> = Response::from_slice(body).unwrap();
+        let cancelled = response.result().unwrap();
+        assert_eq!(
+            cancelled[0].cl_ord_id.to_string(),
+            "89a38056-80f1-45b2-89d3-4d8e3a203a79"
+        );
+
+        // Linear only returns the ids.
+        let body = br#"{"ret_code":0,"ret_msg":"OK","ext_code":"","ext_info":"","result":["89a38056-80f1-45b2-89d3-4d8e3a203a79"],"time_now":"1604653633.173848"}"#;
+        let response: Response<Vec<OrderId>> = Response::from_slice(body).unwrap();
+        assert_eq!(response.result().unwrap().len(), 1);
+
+        // The shapes can't be confused with each other.
+        assert!(Response::<Vec<OrderId>>::from_slice(br#"{"ret_code":0,"ret_msg":"OK","ext_code":"","ext_info":"","result":[{"clOrdID":"89a38056-80f1-45b2-89d3-4d8e3a203a79"}],"time_now":"1604653633.173848"}"#).is_err());
+    }
+}
@@ -0,0 +1,222 @@
+use super::{FetchExecutions, FetchExecutionsOptions};
+use crate::{
+    http::{Client, Result},
+    ExecId, Execution,
+};
+use futures_util::{
+    ready,
+    task::{Context, Poll},
+    Future, Stream,
+};
+use std::collections::{HashSet, VecDeque};
+use std::pin::Pin;
+
+/// Maximum number of executions Bybit returns per request.
+const INVERSE_PAGE_LIMIT: i64 = 50;
+const LINEAR_PAGE_LIMIT: i64 = 200;
+/// Maximum page number of the linear endpoint.
+const LINEAR_MAX_PAGE: i64 = 50;
+
+type Page = Pin<Box<dyn Future<Output = Result<Vec<Execution>>> + Send>>;
+
+/// Stream of the executions of a symbol since a given time, newest first.
+///
+/// The executions are fetched page by page, each request waiting for the rate limit budget of the
+/// client. Executions shifted to the next page by new fills are yielded only once. A failed
+/// request is yielded as an error, polling the stream again retries the same page.
+pub struct ExecutionHistory {
+    client: Client,
+    options: FetchExecutionsOptions,
+    linear: bool,
+    limit: i64,
+    page: i64,
+    seen: HashSet<ExecId>,
+    oldest_time_ms: Option<i64>,
+    buf: VecDeque<Execution>,
+    request: Option<Page>,
+    done: bool,
+}
+
+impl ExecutionHistory {
+    /// Create a stream of the executions since the given time.
+    /// The contract type of the symbol, which determines the endpoint, is looked up in the
+    /// client's symbol registry, falling back to the name of the symbol.
+    /// * `client` - The client used to fetch the executions.
+    /// * `symbol` - The symbol to fetch the executions for.
+    /// * `start_time` - The start time (in milliseconds), `None` for the full history.
+    pub fn new(client: Client, symbol: &str, start_time: Option<i64>) -> Self {
+        let linear = client.contract_type(symbol).is_linear();
+        let limit = if linear {
+            LINEAR_PAGE_LIMIT
+        } else {
+            INVERSE_PAGE_LIMIT
+        };
+        ExecutionHistory {
+            client,
+            options: FetchExecutionsOptions {
+                start_time,
+                ..FetchExecutionsOptions::new(symbol)
+            },
+            linear,
+            limit,
+            page: 1,
+            seen: HashSet::new(),
+            oldest_time_ms: None,
+            buf: VecDeque::new(),
+            request: None,
+            done: false,
+        }
+    }
+
+    /// Returns the execution time (in milliseconds) of the oldest execution fetched so far.
+    pub fn oldest_time_ms(&self) -> Option<i64> {
+        self.oldest_time_ms
+    }
+
+    /// Returns `true` if every execution has been yielded.
+    pub fn is_done(&self) -> bool {
+        self.done && self.buf.is_empty()
+    }
+
+    fn fetch_page(&self) -> Page {
+        let client = self.client.clone();
+        let options = FetchExecutionsOptions {
+            page: Some(self.page),
+            limit: Some(self.limit),
+            ..self.options.clone()
+        };
+        Box::pin(async move { client.fetch_executions(options).await })
+    }
+
+    /// Buffer the new executions of a page and advance to the next page.
+    fn handle_page(&mut self, executions: Vec<Execution>) {
+        let full = executions.len() as i64 >= self.limit;
+        for execution in executions {
+            if self.seen.insert(execution.exec_id.clone()) {
+                self.oldest_time_ms = Some(
+                    self.oldest_time_ms
+                        .map_or(execution.trade_time_ms, |oldest| {
+                            oldest.min(execution.trade_time_ms)
+                        }),
+                );
+                self.buf.push_back(execution);
+            }
+        }
+
+        if !full {
+            self.done = true;
+        } else if self.linear && self.page >= LINEAR_MAX_PAGE {
+            // The linear endpoint serves a limited number of pages, continue with the executions
+            // older than the oldest one so far.
+            self.options.end_time = self.oldest_time_ms;
+            self.page = 1;
+        } else {
+            self.page += 1;
+        }
+    }
+}
+
+impl Stream for ExecutionHistory {
+    type Item = Result<Execution>;
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(execution) = self.buf.pop_front() {
+                return Poll::Ready(Some(Ok(execution)));
+            }
+            if self.done {
+                return Poll::Ready(None);
+            }
+            if self.request.is_none() {
+                let request = self.fetch_page();
+                self.request = Some(request);
+            }
+            let result = ready!(self.request.as_mut().unwrap().as_mut().poll(cx));
+            self.request = None;
+            match result {
+                Ok(executions) => self.handle_page(executions),
+                Err(e) => return Poll::Ready(Some(Err(e))),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::BybitErrorKind;
+    use crate::testing::{MockResponse, MockServer};
+    use futures_util::StreamExt;
+    use serde_json::{json, Value};
+
+    const PATH: &str = "/v2/private/execution/list";
+
+    fn execution(n: i64) -> Value {
+        json!({
+            "exec_id": format!("exec-{}", n),
+            "order_id": format!("order-{}", n),
+            "order_link_id": "",
+            "symbol": "BTCUSD",
+            "side": "Buy",
+            "order_type": "Limit",
+            "order_price": "8000",
+            "order_qty": 1,
+            "exec_type": "Trade",
+            "exec_price": "8000",
+            "exec_qty": 1,
+            "exec_value": "0.000125",
+            "exec_fee": "-0.00000003",
+            "fee_rate": "-0.00025",
+            "leaves_qty": 0,
+            "closed_size": 0,
+            "last_liquidity_ind": "AddedLiquidity",
+            "trade_time_ms": 1_600_000_000_000 - n * 1000,
+        })
+    }
+
+    fn page(range: std::ops::Range<i64>) -> MockResponse {
+        let trade_list: Vec<Value> = range.map(execution).collect();
+        MockResponse::ok(json!({ "order_id": "Abandoned!!", "trade_list": trade_list }))
+    }
+
+    #[tokio::test]
+    async fn history() {
+        let server = MockServer::start().await.unwrap();
+        server.enqueue(PATH, page(0..50));
+        // A new fill shifts the executions by one.
+        server.enqueue(PATH, page(49..99));
+        server.enqueue(
+            PATH,
+            MockResponse::error(BybitErrorKind::SystemBusy.code(), "system busy"),
+        );
+        server.enqueue(PATH, page(99..120));
+        let mut history = ExecutionHistory::new(server.http_client(), "BTCUSD", None);
+
+        let mut executions = Vec::new();
+        while let Some(execution) = history.next().await {
+            match execution {
+                Ok(execution) => executions.push(execution),
+                Err(err) => assert_eq!(err.kind(), Some(BybitErrorKind::SystemBusy)),
+            }
+        }
+        assert_eq!(executions.len(), 120);
+        assert_eq!(executions[119].exec_id.to_string(), "exec-119");
+        assert_eq!(history.oldest_time_ms(), Some(1_600_000_000_000 - 119_000));
+        assert!(history.is_done());
+
+        let pages: Vec<_> = server
+            .requests()
+            .into_iter()
+            .filter(|request| request.path == PATH)
+            .map(|request| request.param("page").unwrap_or("").to_owned())
+            .collect();
+        assert_eq!(pages, ["1", "2", "3", "3"]);
+    }
+
+    #[tokio::test]
+    async fn empty() {
+        let server = MockServer::start().await.unwrap();
+        let history = ExecutionHistory::new(server.http_client(), "BTCUSDT", Some(0));
+        let executions: Vec<_> = history.collect().await;
+        assert!(executions.is_empty());
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{
+    http::{Client, Query, Response, Result},
+    ExecType, Execution, OrderId,
+};
+use async_trait::async_trait;
+use serde::Serialize;
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct FetchExecutionsOptions {
+    pub symbol: String,
+    /// Only the executions of this order (inverse contracts only)
+    pub order_id: Option<OrderId>,
+    /// Executions after this time (in milliseconds)
+    pub start_time: Option<i64>,
+    /// Executions before this time (in milliseconds, linear contracts only)
+    pub end_time: Option<i64>,
+    /// Only the executions of this type (linear contracts only)
+    pub exec_type: Option<ExecType>,
+    /// Page number, starting at 1
+    pub page: Option<i64>,
+    /// Number of executions per page, up to 50 for inverse contracts and 200 for linear contracts
+    pub limit: Option<i64>,
+}
+
+impl FetchExecutionsOptions {
+    /// Create the options for fetching the executions of a symbol.
+    /// * `symbol` - The symbol to fetch the executions for.
+    pub fn new(symbol: &str) -> Self {
+        FetchExecutionsOptions {
+            symbol: symbol.to_owned(),
+            ..Default::default()
+        }
+    }
+}
+
+#[async_trait]
+pub trait FetchExecutions {
+    /// Fetch a page of the executions of a symbol, newest first.
+    /// The endpoint is chosen by the contract type of the symbol.
+    /// * `options` - The options for fetching the executions.
+    async fn fetch_executions(&self, options: FetchExecutionsOptions) -> Result<Vec<Execution>>;
+}
+
+impl Query for FetchExecutionsOptions {}
+
+#[async_trait]
+impl FetchExecutions for Client {
+    async fn fetch_executions(&self, options: FetchExecutionsOptions) -> Result<Vec<Execution>> {
+        if self.contract_type(&options.symbol).is_linear() {
+            let response: Response<response::LinearExecutions> = self
+                .signed_get("/private/linear/trade/execution/list", options)
+                .await?;
+            response
+                .result()
+                .map(|result| result.data.unwrap_or_default())
+        } else {
+            let response: Response<response::Executions> = self
+                .signed_get("/v2/private/execution/list", options)
+                .await?;
+            response
+                .result()
+                .map(|result| result.trade_list.unwrap_or_default())
+        }
+    }
+}
+
+mod response {
+    use crate::Execution;
+    use serde::Deserialize;
+
+    /// The list is `null` when there are no executions.
+    #[derive(Deserialize, Debug)]
+    pub struct Executions {
+        pub trade_list: Option<Vec<Execution>>,
+    }
+
+    #[derive(Deserialize, Debug)]
+    pub struct LinearExecutions {
+        pub data: Option<Vec<Execution>>,
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{
+    http::{Client, Query, Response, Result},
+    FundingFee, FundingRate, PredictedFunding,
+};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait FetchFundingRate {
+    /// Fetch the funding rate of the last funding interval of a perpetual contract.
+    /// The endpoint is chosen by the contract type of the symbol.
+    /// * `symbol` - The symbol to fetch the funding rate for.
+    async fn fetch_funding_rate(&self, symbol: &str) -> Result<FundingRate>;
+}
+
+#[async_trait]
+pub trait FetchFundingFee {
+    /// Fetch the funding fee of the last funding interval of a position.
+    /// The endpoint is chosen by the contract type of the symbol.
+    /// * `symbol` - The symbol of the position.
+    async fn fetch_funding_fee(&self, symbol: &str) -> Result<FundingFee>;
+}
+
+#[async_trait]
+pub trait FetchPredictedFunding {
+    /// Fetch the predicted funding rate and fee of the next funding interval of a position.
+    /// The endpoint is chosen by the contract type of the symbol.
+    /// * `symbol` - The symbol of the position.
+    async fn fetch_predicted_funding(&self, symbol: &str) -> Result<PredictedFunding>;
+}
+
+#[async_trait]
+impl FetchFundingRate for Client {
+    async fn fetch_funding_rate(&self, symbol: &str) -> Result<FundingRate> {
+        let path = if self.contract_type(symbol).is_linear() {
+            "/public/linear/funding/prev-funding-rate"
+        } else {
+            "/v2/public/funding/prev-funding-rate"
+        };
+        let query = request::Funding::new(symbol);
+        let response: Response<FundingRate> = self.get(path, &query).await?;
+        response.result()
+    }
+}
+
+#[async_trait]
+impl FetchFundingFee for Client {
+    async fn fetch_funding_fee(&self, symbol: &str) -> Result<FundingFee> {
+        let path = if self.contract_type(symbol).is_linear() {
+            "/private/linear/funding/prev-funding"
+        } else {
+            "/v2/private/funding/prev-funding"
+        };
+        let query = request::Funding::new(symbol);
+        let response: Response<FundingFee> = self.signed_get(path, query).await?;
+        response.result()
+    }
+}
+
+#[async_trait]
+impl FetchPredictedFunding for Client {
+    async fn fetch_predicted_funding(&self, symbol: &str) -> Result<PredictedFunding> {
+        let path = if self.contract_type(symbol).is_linear() {
+            "/private/linear/funding/predicted-funding"
+        } else {
+            "/v2/private/funding/predicted-funding"
+        };
+        let query = request::Funding::new(symbol);
+        let response: Response<PredictedFunding> = self.signed_get(path, query).await?;
+        response.result()
+    }
+}
+
+mod request {
+    use super::*;
+    use serde::Serialize;
+
+    #[derive(Serialize, Clone)]
+    pub struct Funding {
+        pub symbol: String,
+    }
+
+    impl Funding {
+        pub fn new(symbol: &str) -> Self {
+            Funding {
+                symbol: symbol.to_owned(),
+            }
+        }
+    }
+
+    impl Query for Funding {}
+}
@@ -0,0 +1,182 @@
+use super::{FetchKlines, FetchKlinesOptions};
+use crate::{
+    http::{Client, Result},
+    Interval, Kline,
+};
+use futures_util::{
+    ready,
+    task::{Context, Poll},
+    Future, Stream,
+};
+use std::collections::VecDeque;
+use std::pin::Pin;
+
+/// Maximum number of klines Bybit returns per request.
+const PAGE_LIMIT: i64 = 200;
+
+type Page = Pin<Box<dyn Future<Output = Result<Vec<Kline>>> + Send>>;
+
+/// Stream of the klines of a symbol in the range `[from, to)`, oldest first.
+///
+/// The klines are fetched in pages of 200, each request waiting for the rate limit budget of the
+/// client. Overlapping klines of consecutive pages are yielded only once. A failed request is
+/// yielded as an error, polling the stream again resumes after the last yielded kline.
+pub struct KlineBackfill {
+    client: Client,
+    symbol: String,
+    interval: Interval,
+    /// Start time (in seconds) of the next page
+    from: i64,
+    /// End time (in seconds), exclusive
+    to: i64,
+    last_open_time: Option<i64>,
+    buf: VecDeque<Kline>,
+    page: Option<Page>,
+    done: bool,
+}
+
+impl KlineBackfill {
+    /// Create a backfill of the klines in the range `[from, to)`.
+    /// The contract type of the symbol, which determines the endpoint, is looked up in the
+    /// client's symbol registry, falling back to the name of the symbol.
+    /// * `client` - The client used to fetch the klines.
+    /// * `symbol` - The symbol to fetch the klines for.
+    /// * `interval` - The interval of the klines.
+    /// * `from` - The start time (in seconds), inclusive.
+    /// * `to` - The end time (in seconds), exclusive.
+    pub fn new(client: Client, symbol: &str, interval: Interval, from: i64, to: i64) -> Self {
+        KlineBackfill {
+            client,
+            symbol: symbol.to_owned(),
+            interval,
+            from,
+            to,
+            last_open_time: None,
+            buf: VecDeque::new(),
+            page: None,
+            done: from >= to,
+        }
+    }
+
+    /// Returns the open time (in seconds) of the newest kline fetched so far.
+    pub fn last_open_time(&self) -> Option<i64> {
+        self.last_open_time
+    }
+
+    /// Returns `true` if every kline in the range has been yielded.
+    pub fn is_done(&self) -> bool {
+        self.done && self.buf.is_empty()
+    }
+
+    fn fetch_page(&self) -> Page {
+        let client = self.client.clone();
+        let options = FetchKlinesOptions {
+            limit: Some(PAGE_LIMIT),
+            ..FetchKlinesOptions::new(&self.symbol, self.interval, self.from)
+        };
+        Box::pin(async move { client.fetch_klines(options).await })
+    }
+
+    /// Buffer the new klines of a page and advance to the next page.
+    fn handle_page(&mut self, klines: Vec<Kline>) {
+        let full = klines.len() as i64 >= PAGE_LIMIT;
+        let mut reached_end = false;
+        let mut newest = self.last_open_time;
+        for kline in klines {
+            reached_end |= kline.open_time >= self.to;
+            let is_new = newest.is_none_or(|newest| kline.open_time > newest);
+            if is_new && kline.open_time >= self.from && kline.open_time < self.to {
+                newest = Some(kline.open_time);
+                self.buf.push_back(kline);
+            }
+        }
+
+        match newest {
+            Some(newest) if Some(newest) != self.last_open_time => {
+                self.last_open_time = Some(newest);
+                self.from = newest + 1;
+                self.done = !full || reached_end || self.from >= self.to;
+            }
+            // A page without new klines means there is nothing left to fetch.
+            _ => self.done = true,
+        }
+    }
+}
+
+impl Stream for KlineBackfill {
+    type Item = Result<Kline>;
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(kline) = self.buf.pop_front() {
+                return Poll::Ready(Some(Ok(kline)));
+            }
+            if self.done {
+                return Poll::Ready(None);
+            }
+            if self.page.is_none() {
+                let page = self.fetch_page();
+                self.page = Some(page);
+            }
+            let result = ready!(self.page.as_mut().unwrap().as_mut().poll(cx));
+            self.page = None;
+            match result {
+                Ok(klines) => self.handle_page(klines),
+                Err(e) => return Poll::Ready(Some(Err(e))),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::BybitErrorKind;
+    use crate::testing::{MockResponse, MockServer};
+    use futures_util::StreamExt;
+
+    const FROM: i64 = 1_600_000_000 - 1_600_000_000 % 3600;
+
+    #[tokio::test]
+    async fn backfill() {
+        let server = MockServer::start().await.unwrap();
+        let to = FROM + 500 * 60;
+        let backfill = KlineBackfill::new(server.http_client(), "BTCUSD", Interval::Min1, FROM, to);
+        let klines: Vec<Kline> = backfill.map(|kline| kline.unwrap()).collect().await;
+        assert_eq!(klines.len(), 500);
+        assert!(klines
+            .windows(2)
+            .all(|pair| pair[1].open_time == pair[0].open_time + 60));
+        assert_eq!(klines[0].open_time, FROM);
+        assert_eq!(klines[499].open_time, to - 60);
+
+        let pages: Vec<_> = server
+            .requests()
+            .into_iter()
+            .filter(|request| request.path == "/v2/public/kline/list")
+            .collect();
+        assert_eq!(pages.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn linear_resume() {
+        let server = MockServer::start().await.unwrap();
+        let path = "/public/linear/kline";
+        let to = FROM + 300 * 60;
+        let mut backfill =
+            KlineBackfill::new(server.http_client(), "BTCUSDT", Interval::Min1, FROM, to);
+        for _ in 0..200 {
+            assert!(backfill.next().await.unwrap().is_ok());
+        }
+        server.enqueue(
+            path,
+            MockResponse::error(BybitErrorKind::SystemBusy.code(), "system busy"),
+        );
+        let err = backfill.next().await.unwrap().unwrap_err();
+        assert_eq!(err.kind(), Some(BybitErrorKind::SystemBusy));
+        assert_eq!(backfill.last_open_time(), Some(FROM + 199 * 60));
+
+        let rest: Vec<Kline> = backfill.map(|kline| kline.unwrap()).collect().await;
+        assert_eq!(rest.len(), 100);
+        assert_eq!(rest[0].open_time, FROM + 200 * 60);
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{
+    http::{Client, Query, Response, Result},
+    Interval, Kline, PriceKline,
+};
+use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Serialize};
+
+#[derive(Clone, Debug, Serialize)]
+pub struct FetchKlinesOptions {
+    pub symbol: String,
+    pub interval: Interval,
+    /// Start time (in seconds)
+    pub from: i64,
+    /// Number of klines, up to 200 (default 200)
+    pub limit: Option<i64>,
+}
+
+impl FetchKlinesOptions {
+    /// Create options for fetching klines.
+    /// * `symbol` - The symbol to fetch the klines for.
+    /// * `interval` - The interval of the klines.
+    /// * `from` - The start time (in seconds).
+    pub fn new(symbol: &str, interval: Interval, from: i64) -> Self {
+        FetchKlinesOptions {
+            symbol: symbol.to_owned(),
+            interval,
+            from,
+            limit: None,
+        }
+    }
+}
+
+#[async_trait]
+pub trait FetchKlines {
+    /// Fetch the klines of a symbol.
+    /// The endpoint is chosen by the contract type of the symbol.
+    /// * `options` - The options for fetching the klines.
+    async fn fetch_klines(&self, options: FetchKlinesOptions) -> Result<Vec<Kline>>;
+}
+
+#[async_trait]
+pub trait FetchMarkPriceKlines {
+    /// Fetch the mark price klines.
+    /// * `options` - The options for fetching the klines.
+    async fn fetch_mark_price_klines(&self, options: FetchKlinesOptions)
+        -> Result<Vec<PriceKline>>;
+}
+
+#[async_trait]
+pub trait FetchIndexPriceKlines {
+    /// Fetch the index price klines.
+    /// * `options` - The options for fetching the klines.
+    async fn fetch_index_price_klines(
+        &self,
+        options: FetchKlinesOptions,
+    ) -> Result<Vec<PriceKline>>;
+}
+
+#[async_trait]
+pub trait FetchPremiumIndexKlines {
+    /// Fetch the premium index klines.
+    /// * `options` - The options for fetching the klines.
+    async fn fetch_premium_index_klines(
+        &self,
+        options: FetchKlinesOptions,
+    ) -> Result<Vec<PriceKline>>;
+}
+
+impl Query for FetchKlinesOptions {}
+
+#[async_trait]
+impl FetchKlines for Client {
+    async fn fetch_klines(&self, options: FetchKlinesOptions) -> Result<Vec<Kline>> {
+        let path = if self.contract_type(&options.symbol).is_linear() {
+            "/public/linear/kline"
+        } else {
+            "/v2/public/kline/list"
+        };
+        fetch(self, path, options).await
+    }
+}
+
+#[async_trait]
+impl FetchMarkPriceKlines for Client {
+    async fn fetch_mark_price_klines(
+        &self,
+        options: FetchKlinesOptions,
+    ) -> Result<Vec<PriceKline>> {
+        fetch(self, "/v2/public/mark-price-kline", options).await
+    }
+}
+
+#[async_trait]
+impl FetchIndexPriceKlines for Client {
+    async fn fetch_index_price_klines(
+        &self,
+        options: FetchKlinesOptions,
+    ) -> Result<Vec<PriceKline>> {
+        fetch(self, "/v2/public/index-price-kline", options).await
+    }
+}
+
+#[async_trait]
+impl FetchPremiumIndexKlines for Client {
+    async fn fetch_premium_index_klines(
+        &self,
+        options: FetchKlinesOptions,
+    ) -> Result<Vec<PriceKline>> {
+        fetch(self, "/v2/public/premium-index-kline", options).await
+    }
+}
+
+async fn fetch<T>(client: &Client, path: &str, options: FetchKlinesOptions) -> Result<Vec<T>>
+where
+    T: DeserializeOwned,
+{
+    let response: Response<Vec<T>> = client.get(path, &options).await?;
+    response.result()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query() {
+        let options = FetchKlinesOptions::new("BTCUSD", Interval::Hour4, 1581231300);
+        assert_eq!(
+            serde_urlencoded::to_string(&options).unwrap(),
+            "symbol=BTCUSD&interval=240&from=1581231300"
+        );
+    }
+}
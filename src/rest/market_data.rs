@@ -0,0 +1,110 @@
+use crate::{
+    http::{Client, Query, Response, Result},
+    AccountRatio, BigDeal, DataPeriod, OpenInterest,
+};
+use async_trait::async_trait;
+use serde::Serialize;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct FetchOpenInterestOptions {
+    pub symbol: String,
+    pub period: DataPeriod,
+    /// Number of records, up to 200 (default 50)
+    pub limit: Option<i64>,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct FetchBigDealsOptions {
+    pub symbol: String,
+    /// Number of records, up to 1000 (default 500)
+    pub limit: Option<i64>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct FetchAccountRatioOptions {
+    pub symbol: String,
+    pub period: DataPeriod,
+    /// Number of records, up to 500 (default 50)
+    pub limit: Option<i64>,
+}
+
+#[async_trait]
+pub trait FetchOpenInterest {
+    /// Fetch the open interest history, newest first.
+    /// * `options` - The options for fetching the open interest.
+    async fn fetch_open_interest(
+        &self,
+        options: FetchOpenInterestOptions,
+    ) -> Result<Vec<OpenInterest>>;
+}
+
+#[async_trait]
+pub trait FetchBigDeals {
+    /// Fetch the latest big deals (filled orders worth more than 500,000 USD) of the last 24h.
+    /// * `options` - The options for fetching the big deals.
+    async fn fetch_big_deals(&self, options: FetchBigDealsOptions) -> Result<Vec<BigDeal>>;
+}
+
+#[async_trait]
+pub trait FetchAccountRatio {
+    /// Fetch the long/short ratio of the accounts holding a position, newest first.
+    /// * `options` - The options for fetching the account ratio.
+    async fn fetch_account_ratio(
+        &self,
+        options: FetchAccountRatioOptions,
+    ) -> Result<Vec<AccountRatio>>;
+}
+
+impl Query for FetchOpenInterestOptions {}
+impl Query for FetchBigDealsOptions {}
+impl Query for FetchAccountRatioOptions {}
+
+#[async_trait]
+impl FetchOpenInterest for Client {
+    async fn fetch_open_interest(
+        &self,
+        options: FetchOpenInterestOptions,
+    ) -> Result<Vec<OpenInterest>> {
+        let response: Response<Vec<OpenInterest>> =
+            self.get("/v2/public/open-interest", &options).await?;
+        response.result()
+    }
+}
+
+#[async_trait]
+impl FetchBigDeals for Client {
+    async fn fetch_big_deals(&self, options: FetchBigDealsOptions) -> Result<Vec<BigDeal>> {
+        let response: Response<Vec<BigDeal>> = self.get("/v2/public/big-deal", &options).await?;
+        response.result()
+    }
+}
+
+#[async_trait]
+impl FetchAccountRatio for Client {
+    async fn fetch_account_ratio(
+        &self,
+        options: FetchAccountRatioOptions,
+    ) -> Result<Vec<AccountRatio>> {
+        let response: Response<Vec<AccountRatio>> =
+            self.get("/v2/public/account-ratio", &options).await?;
+        response.result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query() {
+        let options = FetchOpenInterestOptions {
+            symbol: "BTCUSD".to_owned(),
+            period: DataPeriod::Hour1,
+            limit: Some(10),
+        };
+        assert_eq!(
+            serde_urlencoded::to_string(&options).unwrap(),
+            "symbol=BTCUSD&period=1h&limit=10"
+        );
+    }
+}
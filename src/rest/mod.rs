@@ -1,14 +1,40 @@
+mod account;
 mod announcements;
+mod closed_pnl;
+mod conditional_orders;
+mod execution_history;
+mod executions;
+mod funding;
+mod kline_backfill;
+mod klines;
 mod liquidations;
+mod market_data;
+mod order_book;
 mod orders;
+mod positions;
+mod risk_limits;
 mod server_time;
 mod symbols;
 mod tickers;
+mod trades;
 mod wallets;
+pub use account::*;
 pub use announcements::*;
+pub use closed_pnl::*;
+pub use conditional_orders::*;
+pub use execution_history::*;
+pub use executions::*;
+pub use funding::*;
+pub use kline_backfill::*;
+pub use klines::*;
 pub use liquidations::*;
+pub use market_data::*;
+pub use order_book::*;
 pub use orders::*;
+pub use positions::*;
+pub use risk_limits::*;
 pub use server_time::*;
 pub use symbols::*;
 pub use tickers::*;
+pub use trades::*;
 pub use wallets::*;
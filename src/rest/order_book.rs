@@ -0,0 +1,35 @@
+use crate::{
+    http::{Client, Query, Response, Result},
+    OrderBookL2,
+};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait FetchOrderBook {
+    /// Fetch the order book (up to 25 levels per side) of a given symbol.
+    /// * `symbol` - The symbol of the order book.
+    async fn fetch_order_book(&self, symbol: &str) -> Result<OrderBookL2>;
+}
+
+#[async_trait]
+impl FetchOrderBook for Client {
+    async fn fetch_order_book(&self, symbol: &str) -> Result<OrderBookL2> {
+        let query = query::OrderBook {
+            symbol: symbol.to_owned(),
+        };
+        let response: Response<OrderBookL2> = self.get("/v2/public/orderBook/L2", &query).await?;
+        response.result()
+    }
+}
+
+mod query {
+    use super::Query;
+    use serde::Serialize;
+
+    #[derive(Serialize)]
+    pub struct OrderBook {
+        pub symbol: String,
+    }
+
+    impl Query for OrderBook {}
+}
@@ -1,20 +1,29 @@
 use crate::{
-    http::{Client, Query, Response, Result},
+    http::{BybitErrorKind, Client, Query, Response, Result},
     order::*,
+    Price, Qty, Symbol,
 };
 use async_trait::async_trait;
-use serde::Serialize;
+use serde::{
+    de::{DeserializeOwned, IgnoredAny},
+    Serialize,
+};
+use std::sync::Arc;
 
 #[derive(Serialize, Clone, Debug, Default)]
 pub struct ListActiveOrdersFilter {
     pub symbol: String,
     pub order_status: Option<OrderStatus>,
+    /// Page direction, `prev` or `next` (inverse contracts only)
     pub direction: Option<String>,
     pub limit: Option<i64>,
+    /// Page cursor (inverse contracts only)
     pub cursor: Option<String>,
+    /// Page number, starting at 1 (linear contracts only)
+    pub page: Option<i64>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Clone, Debug)]
 #[serde(rename_all = "snake_case")]
 pub enum ActiveOrderId {
     OrderId(OrderId),
@@ -53,22 +62,22 @@ impl From<OrderLinkId> for ActiveOrderId {
 pub struct PlaceActiveOrderData {
     pub symbol: String,
     pub side: Side,
-    pub qty: f64,
+    pub qty: Qty,
     pub order_type: OrderType,
-    pub price: Option<f64>,
+    pub price: Option<Price>,
     pub time_in_force: TimeInForce,
     pub close_on_trigger: Option<bool>,
     pub order_link_id: Option<OrderLinkId>,
-    pub take_profit: Option<f64>,
-    pub stop_loss: Option<f64>,
+    pub take_profit: Option<Price>,
+    pub stop_loss: Option<Price>,
     pub tp_trigger_by: Option<TriggerPrice>,
     pub sl_trigger_by: Option<TriggerPrice>,
     // linear active specifics
     pub reduce_only: Option<bool>,
     pub position_idx: Option<i64>,
     // conditional specifics
-    pub base_price: Option<String>,
-    pub stop_px: Option<String>,
+    pub base_price: Option<Price>,
+    pub stop_px: Option<Price>,
     pub trigger_by: Option<TriggerPrice>,
 }
 
@@ -76,19 +85,19 @@ pub struct PlaceActiveOrderData {
 pub struct PlaceLinearConditionalOrderData {
     pub symbol: String,
     pub side: Side,
-    pub qty: f64,
+    pub qty: Qty,
     pub order_type: OrderType,
-    pub price: Option<f64>,
+    pub price: Option<Price>,
     pub time_in_force: TimeInForce,
     pub close_on_trigger: bool,
     pub order_link_id: Option<OrderLinkId>,
-    pub take_profit: Option<f64>,
-    pub stop_loss: Option<f64>,
+    pub take_profit: Option<Price>,
+    pub stop_loss: Option<Price>,
     pub tp_trigger_by: Option<TriggerPrice>,
     pub sl_trigger_by: Option<TriggerPrice>,
     // conditional specifics
-    pub base_price: f64,
-    pub stop_px: f64,
+    pub base_price: Price,
+    pub stop_px: Price,
     pub trigger_by: Option<TriggerPrice>,
     // linear conditional specifics
     pub reduce_only: bool,
@@ -97,7 +106,7 @@ pub struct PlaceLinearConditionalOrderData {
 
 pub struct UpdateOrderData {
     pub symbol: String,
-    pub price: f64,
+    pub price: Price,
 }
 
 #[async_trait]
@@ -105,6 +114,11 @@ pub trait ListActiveOrders {
     async fn list_orders(&self, filter: ListActiveOrdersFilter) -> Result<Vec<Order>>;
 }
 
+#[async_trait]
+pub trait ListActiveLinearOrders {
+    async fn list_linear_orders(&self, filter: ListActiveOrdersFilter) -> Result<Vec<LinearOrder>>;
+}
+
 #[async_trait]
 pub trait PlaceActiveOrder {
     async fn place_active_order(&self, data: PlaceActiveOrderData) -> Result<Order>;
@@ -115,6 +129,8 @@ pub trait PlaceActiveLinearOrder {
     async fn place_active_linear_order(&self, data: PlaceActiveOrderData) -> Result<LinearOrder>;
 }
 
+/// Replace the price of an active order.
+/// The endpoint is chosen by the contract type of the symbol.
 #[async_trait]
 pub trait UpdateOrders {
     async fn update_order(
@@ -124,6 +140,9 @@ pub trait UpdateOrders {
     ) -> Result<OrderId>;
 }
 
+/// Cancel one active order, or all active orders of a symbol. Cancelling all orders returns the
+/// IDs of the cancelled orders.
+/// The endpoint is chosen by the contract type of the symbol.
 #[async_trait]
 pub trait CancelOrders {
     async fn cancel_active_order(&self, active_order_id: ActiveOrderId, symbol: &str)
@@ -140,6 +159,15 @@ pub trait QueryActiveOrder {
     ) -> Result<Option<Order>>;
 }
 
+#[async_trait]
+pub trait QueryActiveLinearOrder {
+    async fn query_active_linear_order(
+        &self,
+        active_order_id: ActiveOrderId,
+        symbol: &str,
+    ) -> Result<Option<LinearOrder>>;
+}
+
 #[async_trait]
 pub trait PlaceConditionalOrder {
     async fn place_conditional_order(&self, data: PlaceActiveOrderData)
@@ -158,31 +186,53 @@ pub trait PlaceLinearConditionalOrder {
 impl ListActiveOrders for Client {
     async fn list_orders(&self, filter: ListActiveOrdersFilter) -> Result<Vec<Order>> {
         let query = request::ListActiveOrders { filter };
-        let query = self.sign_query(query);
         let response: Response<response::ListActiveOrders> =
-            self.get("/v2/private/order/list", &query).await?;
+            self.signed_get("/v2/private/order/list", query).await?;
         response.result().map(|res| res.orders)
     }
 }
 
+#[async_trait]
+impl ListActiveLinearOrders for Client {
+    async fn list_linear_orders(&self, filter: ListActiveOrdersFilter) -> Result<Vec<LinearOrder>> {
+        let query = request::ListActiveOrders { filter };
+        let response: Response<response::ListActiveLinearOrders> =
+            self.signed_get("/private/linear/order/list", query).await?;
+        response.result().map(|res| res.orders.unwrap_or_default())
+    }
+}
+
 #[async_trait]
 impl PlaceActiveOrder for Client {
     async fn place_active_order(&self, data: PlaceActiveOrderData) -> Result<Order> {
+        validate_order(self, &data)?;
         let query: request::CreateOrder = data.into();
-        let query = self.sign_query(query);
-        let response: Response<Order> = self.post("/v2/private/order/create", &query).await?;
-        response.result()
+        place_order(
+            self,
+            "/v2/private/order/create",
+            "/v2/private/order",
+            query.symbol.clone(),
+            query.order_link_id.clone(),
+            query,
+        )
+        .await
     }
 }
 
 #[async_trait]
 impl PlaceActiveLinearOrder for Client {
     async fn place_active_linear_order(&self, data: PlaceActiveOrderData) -> Result<LinearOrder> {
+        validate_order(self, &data)?;
         let query: request::CreateOrder = data.into();
-        let query = self.sign_query(query);
-        let response: Response<LinearOrder> =
-            self.post("/private/linear/order/create", &query).await?;
-        response.result()
+        place_order(
+            self,
+            "/private/linear/order/create",
+            "/private/linear/order/search",
+            query.symbol.clone(),
+            query.order_link_id.clone(),
+            query,
+        )
+        .await
     }
 }
 
@@ -193,14 +243,17 @@ impl UpdateOrders for Client {
         active_order_id: ActiveOrderId,
         data: UpdateOrderData,
     ) -> Result<OrderId> {
+        let path = if self.contract_type(&data.symbol).is_linear() {
+            "/private/linear/order/replace"
+        } else {
+            "/v2/private/order/replace"
+        };
         let query = request::UpdateOrder {
             active_order_id,
             symbol: data.symbol.clone(),
             price: data.price,
         };
-        let query = self.sign_query(query);
-        let response: Response<response::UpdateOrder> =
-            self.post("/v2/private/order/replace", &query).await?;
+        let response: Response<response::UpdateOrder> = self.signed_post(path, query).await?;
         response.result().map(|res| res.order_id)
     }
 }
@@ -212,23 +265,33 @@ impl CancelOrders for Client {
         active_order_id: ActiveOrderId,
         symbol: &str,
     ) -> Result<()> {
+        let path = if self.contract_type(symbol).is_linear() {
+            "/private/linear/order/cancel"
+        } else {
+            "/v2/private/order/cancel"
+        };
         let query = request::CancelOrder {
             active_order_id,
             symbol: symbol.to_owned(),
         };
-        let query = self.sign_query(query);
-        let _response: Response<response::CancelOrder> =
-            self.post("/v2/private/order/cancel", &query).await?;
-        Ok(())
+        let response: Response<IgnoredAny> = self.signed_post(path, query).await?;
+        response.result().map(|_| ())
     }
 
     async fn cancel_all_active_orders(&self, symbol: &str) -> Result<Vec<OrderId>> {
         let query = request::CancelAllOrders {
             symbol: symbol.to_owned(),
         };
-        let query = self.sign_query(query);
-        let response: Response<response::CancelAllOrders> =
-            self.post("/v2/private/order/cancelAll", &query).await?;
+        if self.contract_type(symbol).is_linear() {
+            // The linear endpoint returns the ids of the cancelled orders.
+            let response: Response<Vec<OrderId>> = self
+                .signed_post("/private/linear/order/cancel-all", query)
+                .await?;
+            return response.result();
+        }
+        let response: Response<response::CancelAllOrders> = self
+            .signed_post("/v2/private/order/cancelAll", query)
+            .await?;
         response.result().map(|res| {
             res.orders
                 .iter()
@@ -249,9 +312,35 @@ impl QueryActiveOrder for Client {
             active_order_id,
             symbol: symbol.to_owned(),
         };
-        let query = self.sign_query(query);
-        let response: Response<Order> = self.get("/v2/private/order", &query).await?;
-        Ok(response.result)
+        let response: Response<Order> = self.signed_get("/v2/private/order", query).await?;
+        existing_order(response)
+    }
+}
+
+#[async_trait]
+impl QueryActiveLinearOrder for Client {
+    async fn query_active_linear_order(
+        &self,
+        active_order_id: ActiveOrderId,
+        symbol: &str,
+    ) -> Result<Option<LinearOrder>> {
+        let query = request::QueryActiveOrder {
+            active_order_id,
+            symbol: symbol.to_owned(),
+        };
+        let response: Response<LinearOrder> = self
+            .signed_get("/private/linear/order/search", query)
+            .await?;
+        existing_order(response)
+    }
+}
+
+/// Returns the order of a query response, or `None` if Bybit doesn't know the order.
+pub(super) fn existing_order<T>(response: Response<T>) -> Result<Option<T>> {
+    use BybitErrorKind::*;
+    match BybitErrorKind::from_code(response.ret_code) {
+        OrderNotExists | OrderNotFound | LinearOrderNotExists => Ok(None),
+        _ => Ok(response.check()?.result),
     }
 }
 
@@ -261,11 +350,17 @@ impl PlaceConditionalOrder for Client {
         &self,
         data: PlaceActiveOrderData,
     ) -> Result<ConditionalOrder> {
+        validate_order(self, &data)?;
         let query: request::CreateOrder = data.into();
-        let query = self.sign_query(query);
-        let response: Response<ConditionalOrder> =
-            self.post("/v2/private/stop-order/create", &query).await?;
-        response.result()
+        place_order(
+            self,
+            "/v2/private/stop-order/create",
+            "/v2/private/stop-order",
+            query.symbol.clone(),
+            query.order_link_id.clone(),
+            query,
+        )
+        .await
     }
 }
 
@@ -275,42 +370,121 @@ impl PlaceLinearConditionalOrder for Client {
         &self,
         data: PlaceLinearConditionalOrderData,
     ) -> Result<ConditionalOrder> {
+        if let Some(symbol) = registered_symbol(self, &data.symbol) {
+            symbol.validate_qty(data.qty)?;
+            let prices = [
+                ("price", data.price),
+                ("take_profit", data.take_profit),
+                ("stop_loss", data.stop_loss),
+                ("base_price", Some(data.base_price)),
+                ("stop_px", Some(data.stop_px)),
+            ];
+            for (field, price) in prices.iter() {
+                if let Some(price) = price {
+                    symbol.validate_price(field, *price)?;
+                }
+            }
+        }
         let query: request::CreateLinearConditionalOrder = data.into();
-        let query = self.sign_query(query);
-        let response: Response<ConditionalOrder> = self
-            .post("/private/linear/stop-order/create", &query)
-            .await?;
-        response.result()
+        place_order(
+            self,
+            "/private/linear/stop-order/create",
+            "/private/linear/stop-order/search",
+            query.symbol.clone(),
+            query.order_link_id.clone(),
+            query,
+        )
+        .await
     }
 }
 
+/// Returns the symbol of the client's registry with the given name, if any.
+fn registered_symbol(client: &Client, symbol: &str) -> Option<Arc<Symbol>> {
+    client.symbol_registry()?.get(symbol)
+}
+
+/// Validate an order against the client's registry, orders for unknown symbols are not validated.
+fn validate_order(client: &Client, data: &PlaceActiveOrderData) -> Result<()> {
+    match registered_symbol(client, &data.symbol) {
+        Some(symbol) => Ok(symbol.validate_order(data, None)?),
+        None => Ok(()),
+    }
+}
+
+/// Place an order, retrying according to the client's retry policy.
+/// Orders without an `order_link_id` are never retried, since a duplicate can't be detected.
+/// Before every retry the order is looked up by its `order_link_id`, in case a previous attempt
+/// did reach Bybit.
+async fn place_order<Q, T>(
+    client: &Client,
+    path: &str,
+    search_path: &str,
+    symbol: String,
+    order_link_id: Option<OrderLinkId>,
+    query: Q,
+) -> Result<T>
+where
+    Q: Query + Clone + Send + Sync,
+    T: DeserializeOwned + Send,
+{
+    let order_link_id = match order_link_id {
+        Some(order_link_id) => order_link_id,
+        None => {
+            let query = client.sign_query(query);
+            let response: Response<T> = client.post(path, &query).await?;
+            return response.result();
+        }
+    };
+
+    let response: Response<T> = client
+        .retry(|attempt| {
+            let query = query.clone();
+            let search = request::QueryActiveOrder {
+                active_order_id: order_link_id.clone().into(),
+                symbol: symbol.clone(),
+            };
+            async move {
+                if attempt > 0 {
+                    let existing: Response<T> = client.signed_get(search_path, search).await?;
+                    if existing.is_ok() && existing.result.is_some() {
+                        return Ok(existing);
+                    }
+                }
+                let query = client.sign_query(query);
+                client.post(path, &query).await
+            }
+        })
+        .await?;
+    response.result()
+}
+
 mod request {
     use super::*;
     use serde::Serialize;
 
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
     #[serde(transparent)]
     pub struct ListActiveOrders {
         pub filter: ListActiveOrdersFilter,
     }
 
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
     pub struct CreateOrder {
         pub symbol: String,
         pub side: Side,
-        pub qty: f64,
+        pub qty: Qty,
         pub order_type: OrderType,
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub price: Option<f64>,
+        pub price: Option<Price>,
         pub time_in_force: TimeInForce,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub close_on_trigger: Option<bool>,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub order_link_id: Option<OrderLinkId>,
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub take_profit: Option<f64>,
+        pub take_profit: Option<Price>,
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub stop_loss: Option<f64>,
+        pub stop_loss: Option<Price>,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub tp_trigger_by: Option<TriggerPrice>,
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -322,57 +496,57 @@ mod request {
         pub position_idx: Option<i64>,
         //conditional specifics
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub base_price: Option<String>,
+        pub base_price: Option<Price>,
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub stop_px: Option<String>,
+        pub stop_px: Option<Price>,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub trigger_by: Option<TriggerPrice>,
     }
 
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
     pub struct UpdateOrder {
         #[serde(flatten)]
         pub active_order_id: ActiveOrderId,
         pub symbol: String,
         #[serde(rename = "p_r_price")]
-        pub price: f64,
+        pub price: Price,
     }
 
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
     pub struct CancelOrder {
         #[serde(flatten)]
         pub active_order_id: ActiveOrderId,
         pub symbol: String,
     }
 
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
     pub struct CancelAllOrders {
         pub symbol: String,
     }
 
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
     pub struct QueryActiveOrder {
         #[serde(flatten)]
         pub active_order_id: ActiveOrderId,
         pub symbol: String,
     }
 
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
     pub struct CreateLinearConditionalOrder {
         pub symbol: String,
         pub side: Side,
-        pub qty: f64,
+        pub qty: Qty,
         pub order_type: OrderType,
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub price: Option<f64>,
+        pub price: Option<Price>,
         pub time_in_force: TimeInForce,
         pub close_on_trigger: bool,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub order_link_id: Option<OrderLinkId>,
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub take_profit: Option<f64>,
+        pub take_profit: Option<Price>,
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub stop_loss: Option<f64>,
+        pub stop_loss: Option<Price>,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub tp_trigger_by: Option<TriggerPrice>,
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -381,8 +555,8 @@ mod request {
         #[serde(skip_serializing_if = "Option::is_none")]
         pub position_idx: Option<i64>,
         //conditional specifics
-        pub base_price: f64,
-        pub stop_px: f64,
+        pub base_price: Price,
+        pub stop_px: Price,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub trigger_by: Option<TriggerPrice>,
     }
@@ -445,7 +619,7 @@ mod request {
 }
 
 mod response {
-    use super::{Order, OrderId};
+    use super::{LinearOrder, Order, OrderId};
     use serde::Deserialize;
 
     #[derive(Deserialize)]
@@ -454,13 +628,15 @@ mod response {
         pub orders: Vec<Order>,
     }
 
+    /// The list is `null` when a symbol has no orders.
     #[derive(Deserialize)]
-    pub struct UpdateOrder {
-        pub order_id: OrderId,
+    pub struct ListActiveLinearOrders {
+        #[serde(rename = "data")]
+        pub orders: Option<Vec<LinearOrder>>,
     }
 
     #[derive(Deserialize)]
-    pub struct CancelOrder {
+    pub struct UpdateOrder {
         pub order_id: OrderId,
     }
 
@@ -476,3 +652,20 @@ mod response {
         pub cl_ord_id: OrderId,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_error() {
+        let response: Response<Order> =
+            Response::from_slice(include_bytes!("fixtures/order_not_found.json")).unwrap();
+        assert!(existing_order(response).unwrap().is_none());
+
+        let response: Response<Order> =
+            Response::from_slice(include_bytes!("fixtures/order_error.json")).unwrap();
+        let err = existing_order(response).unwrap_err();
+        assert_eq!(err.kind(), Some(BybitErrorKind::InvalidSign));
+    }
+}
@@ -0,0 +1,321 @@
+use crate::{
+    http::{Client, Query, Response, Result},
+    Position, Price, Qty, Side, TriggerPrice,
+};
+use async_trait::async_trait;
+use serde::de::IgnoredAny;
+
+#[derive(Debug, Default, Clone)]
+pub struct TradingStopData {
+    pub symbol: String,
+    /// Position side (linear contracts only)
+    pub side: Option<Side>,
+    /// Take profit price, 0 to cancel
+    pub take_profit: Option<Price>,
+    /// Stop loss price, 0 to cancel
+    pub stop_loss: Option<Price>,
+    /// Trailing stop (distance from the market price), 0 to cancel
+    pub trailing_stop: Option<Price>,
+    pub tp_trigger_by: Option<TriggerPrice>,
+    pub sl_trigger_by: Option<TriggerPrice>,
+    /// Trailing stop activation price (inverse contracts only)
+    pub new_trailing_active: Option<Price>,
+    /// Take profit quantity, for partial take profit (linear contracts only)
+    pub tp_size: Option<Qty>,
+    /// Stop loss quantity, for partial stop loss (linear contracts only)
+    pub sl_size: Option<Qty>,
+}
+
+#[async_trait]
+pub trait ListPositions {
+    /// Fetch the positions of a symbol, one per side for linear contracts.
+    /// The endpoint is chosen by the contract type of the symbol.
+    /// * `symbol` - The symbol to fetch the positions for, `None` for the positions of all
+    ///   inverse and linear symbols.
+    async fn list_positions(&self, symbol: Option<&str>) -> Result<Vec<Position>>;
+}
+
+#[async_trait]
+pub trait SetLeverage {
+    /// Set the leverage of a position, of both positions for linear contracts.
+    /// The endpoint is chosen by the contract type of the symbol.
+    /// * `symbol` - The symbol of the position.
+    /// * `leverage` - The leverage, 0 for cross margin (inverse contracts only).
+    async fn set_leverage(&self, symbol: &str, leverage: f64) -> Result<()>;
+}
+
+#[async_trait]
+pub trait ChangePositionMargin {
+    /// Add margin to, or remove margin from, an isolated position.
+    /// The endpoint is chosen by the contract type of the symbol.
+    /// * `symbol` - The symbol of the position.
+    /// * `side` - The side of the position, required for linear contracts.
+    /// * `margin` - The margin to add, negative to remove margin.
+    async fn change_position_margin(
+        &self,
+        symbol: &str,
+        side: Option<Side>,
+        margin: Qty,
+    ) -> Result<()>;
+}
+
+#[async_trait]
+pub trait SetTradingStop {
+    /// Set the take profit, stop loss or trailing stop of a position.
+    /// The endpoint is chosen by the contract type of the symbol.
+    /// * `data` - The trading stop to set, `side` is required for linear contracts.
+    async fn set_trading_stop(&self, data: TradingStopData) -> Result<()>;
+}
+
+#[async_trait]
+impl ListPositions for Client {
+    async fn list_positions(&self, symbol: Option<&str>) -> Result<Vec<Position>> {
+        let symbol = match symbol {
+            Some(symbol) => symbol,
+            None => {
+                let mut positions = list(self, "/v2/private/position/list", None).await?;
+                positions.extend(list(self, "/private/linear/position/list", None).await?);
+                return Ok(positions);
+            }
+        };
+        let path = if self.contract_type(symbol).is_linear() {
+            "/private/linear/position/list"
+        } else {
+            "/v2/private/position/list"
+        };
+        list(self, path, Some(symbol)).await
+    }
+}
+
+async fn list(client: &Client, path: &str, symbol: Option<&str>) -> Result<Vec<Position>> {
+    let query = request::ListPositions {
+        symbol: symbol.map(str::to_owned),
+    };
+    let response: Response<response::Positions> = client.signed_get(path, query).await?;
+    response.result().map(Into::into)
+}
+
+#[async_trait]
+impl SetLeverage for Client {
+    async fn set_leverage(&self, symbol: &str, leverage: f64) -> Result<()> {
+        let response: Response<IgnoredAny> = if self.contract_type(symbol).is_linear() {
+            let query = request::SetLinearLeverage {
+                symbol: symbol.to_owned(),
+                buy_leverage: leverage,
+                sell_leverage: leverage,
+            };
+            self.signed_post("/private/linear/position/set-leverage", query)
+                .await?
+        } else {
+            let query = request::SetLeverage {
+                symbol: symbol.to_owned(),
+                leverage,
+            };
+            self.signed_post("/v2/private/position/leverage/save", query)
+                .await?
+        };
+        response.check().map(|_| ())
+    }
+}
+
+#[async_trait]
+impl ChangePositionMargin for Client {
+    async fn change_position_margin(
+        &self,
+        symbol: &str,
+        side: Option<Side>,
+        margin: Qty,
+    ) -> Result<()> {
+        let path = if self.contract_type(symbol).is_linear() {
+            "/private/linear/position/add-margin"
+        } else {
+            "/v2/private/position/change-position-margin"
+        };
+        let query = request::ChangePositionMargin {
+            symbol: symbol.to_owned(),
+            side,
+            margin,
+        };
+        // Changing the margin is not idempotent, a retry could apply it twice.
+        let query = self.sign_query(query);
+        let response: Response<IgnoredAny> = self.post(path, &query).await?;
+        response.check().map(|_| ())
+    }
+}
+
+#[async_trait]
+impl SetTradingStop for Client {
+    async fn set_trading_stop(&self, data: TradingStopData) -> Result<()> {
+        let path = if self.contract_type(&data.symbol).is_linear() {
+            "/private/linear/position/trading-stop"
+        } else {
+            "/v2/private/position/trading-stop"
+        };
+        let query: request::TradingStop = data.into();
+        let response: Response<IgnoredAny> = self.signed_post(path, query).await?;
+        response.check().map(|_| ())
+    }
+}
+
+mod request {
+    use super::*;
+    use serde::Serialize;
+
+    #[derive(Serialize, Clone)]
+    pub struct ListPositions {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub symbol: Option<String>,
+    }
+
+    #[derive(Serialize, Clone)]
+    pub struct SetLeverage {
+        pub symbol: String,
+        pub leverage: f64,
+    }
+
+    #[derive(Serialize, Clone)]
+    pub struct SetLinearLeverage {
+        pub symbol: String,
+        pub buy_leverage: f64,
+        pub sell_leverage: f64,
+    }
+
+    #[derive(Serialize, Clone)]
+    pub struct ChangePositionMargin {
+        pub symbol: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub side: Option<Side>,
+        pub margin: Qty,
+    }
+
+    #[derive(Serialize, Clone)]
+    pub struct TradingStop {
+        pub symbol: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub side: Option<Side>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub take_profit: Option<Price>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub stop_loss: Option<Price>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub trailing_stop: Option<Price>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub tp_trigger_by: Option<TriggerPrice>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub sl_trigger_by: Option<TriggerPrice>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub new_trailing_active: Option<Price>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub tp_size: Option<Qty>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub sl_size: Option<Qty>,
+    }
+
+    impl Query for ListPositions {}
+    impl Query for SetLeverage {}
+    impl Query for SetLinearLeverage {}
+    impl Query for ChangePositionMargin {}
+    impl Query for TradingStop {}
+
+    impl From<TradingStopData> for TradingStop {
+        fn from(data: TradingStopData) -> Self {
+            TradingStop {
+                symbol: data.symbol,
+                side: data.side,
+                take_profit: data.take_profit,
+                stop_loss: data.stop_loss,
+                trailing_stop: data.trailing_stop,
+                tp_trigger_by: data.tp_trigger_by,
+                sl_trigger_by: data.sl_trigger_by,
+                new_trailing_active: data.new_trailing_active,
+                tp_size: data.tp_size,
+                sl_size: data.sl_size,
+            }
+        }
+    }
+}
+
+mod response {
+    use super::*;
+    use serde::Deserialize;
+
+    /// The position list endpoints return a single position (inverse) or a list of positions
+    /// (linear) when queried for a symbol, and a list of wrapped positions otherwise.
+    #[derive(Deserialize, Debug)]
+    #[serde(untagged)]
+    pub enum Positions {
+        Wrapped(Vec<WrappedPosition>),
+        List(Vec<Position>),
+        Single(Box<Position>),
+    }
+
+    #[derive(Deserialize, Debug)]
+    pub struct WrappedPosition {
+        pub data: Position,
+        pub is_valid: bool,
+    }
+
+    impl From<Positions> for Vec<Position> {
+        fn from(positions: Positions) -> Self {
+            match positions {
+                Positions::Wrapped(positions) => positions
+                    .into_iter()
+                    .filter(|position| position.is_valid)
+                    .map(|position| position.data)
+                    .collect(),
+                Positions::List(positions) => positions,
+                Positions::Single(position) => vec![*position],
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use response::Positions;
+    use serde_json::json;
+
+    fn position() -> serde_json::Value {
+        let response: serde_json::Value =
+            serde_json::from_slice(include_bytes!("fixtures/position_list.json")).unwrap();
+        response["result"][0]["data"].clone()
+    }
+
+    #[test]
+    fn positions_response() {
+        let response: Response<Positions> =
+            Response::from_slice(include_bytes!("fixtures/position_list.json")).unwrap();
+        let positions: Vec<Position> = response.result().unwrap().into();
+        assert_eq!(positions.len(), 1);
+        assert_eq!(positions[0].symbol, "BTCUSD");
+    }
+
+    #[test]
+    fn positions_shapes() {
+        let position = position();
+
+        // Inverse, queried for a symbol.
+        let positions: Positions = serde_json::from_value(position.clone()).unwrap();
+        assert!(matches!(positions, Positions::Single(_)));
+        assert_eq!(Vec::<Position>::from(positions).len(), 1);
+
+        // Linear, queried for a symbol: one position per side.
+        let positions: Positions = serde_json::from_value(json!([position, position])).unwrap();
+        assert!(matches!(positions, Positions::List(_)));
+        assert_eq!(Vec::<Position>::from(positions).len(), 2);
+
+        // All symbols, invalid entries are dropped.
+        let positions: Positions = serde_json::from_value(json!([
+            { "data": position, "is_valid": true },
+            { "data": position, "is_valid": false },
+        ]))
+        .unwrap();
+        assert!(matches!(positions, Positions::Wrapped(_)));
+        assert_eq!(Vec::<Position>::from(positions).len(), 1);
+
+        // An empty list is a list of wrapped positions, which is empty either way.
+        let positions: Positions = serde_json::from_value(json!([])).unwrap();
+        assert!(Vec::<Position>::from(positions).is_empty());
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{
+    http::{Client, Query, Response, Result},
+    RiskLimit, Side,
+};
+use async_trait::async_trait;
+use serde::de::IgnoredAny;
+
+#[async_trait]
+pub trait FetchRiskLimits {
+    /// Fetch the risk limit tiers of a symbol.
+    /// The endpoint is chosen by the contract type of the symbol.
+    /// * `symbol` - The symbol to fetch the risk limits for, `None` for all inverse symbols.
+    async fn fetch_risk_limits(&self, symbol: Option<&str>) -> Result<Vec<RiskLimit>>;
+}
+
+#[async_trait]
+pub trait SetRiskLimit {
+    /// Set the risk limit of a position.
+    /// The endpoint is chosen by the contract type of the symbol.
+    /// * `symbol` - The symbol of the position.
+    /// * `side` - The side of the position, required for linear contracts.
+    /// * `risk_id` - The ID of the risk limit tier.
+    async fn set_risk_limit(&self, symbol: &str, side: Option<Side>, risk_id: i64) -> Result<()>;
+}
+
+#[async_trait]
+impl FetchRiskLimits for Client {
+    async fn fetch_risk_limits(&self, symbol: Option<&str>) -> Result<Vec<RiskLimit>> {
+        let path = match symbol {
+            Some(symbol) if self.contract_type(symbol).is_linear() => "/public/linear/risk-limit",
+            _ => "/v2/public/risk-limit/list",
+        };
+        let query = request::FetchRiskLimits {
+            symbol: symbol.map(str::to_owned),
+        };
+        let response: Response<Vec<RiskLimit>> = self.get(path, &query).await?;
+        response.result()
+    }
+}
+
+#[async_trait]
+impl SetRiskLimit for Client {
+    async fn set_risk_limit(&self, symbol: &str, side: Option<Side>, risk_id: i64) -> Result<()> {
+        let path = if self.contract_type(symbol).is_linear() {
+            "/private/linear/position/set-risk"
+        } else {
+            "/v2/private/position/risk-limit"
+        };
+        let query = request::SetRiskLimit {
+            symbol: symbol.to_owned(),
+            side,
+            risk_id,
+        };
+        let response: Response<IgnoredAny> = self.signed_post(path, query).await?;
+        response.check().map(|_| ())
+    }
+}
+
+mod request {
+    use super::*;
+    use serde::Serialize;
+
+    #[derive(Serialize, Clone)]
+    pub struct FetchRiskLimits {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub symbol: Option<String>,
+    }
+
+    #[derive(Serialize, Clone)]
+    pub struct SetRiskLimit {
+        pub symbol: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub side: Option<Side>,
+        pub risk_id: i64,
+    }
+
+    impl Query for FetchRiskLimits {}
+    impl Query for SetRiskLimit {}
+}
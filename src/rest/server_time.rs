@@ -11,6 +11,6 @@ impl ServerTime for Client {
     async fn server_time(&self) -> Result<String> {
         let query = NoQuery::new();
         let response: Response<Ignore> = self.get("/v2/public/time", &query).await?;
-        Ok(response.time_now)
+        response.check().map(|res| res.time_now)
     }
 }
@@ -0,0 +1,44 @@
+use crate::{
+    http::{Client, Query, Response, Result},
+    PublicTrade,
+};
+use async_trait::async_trait;
+use serde::Serialize;
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct FetchRecentTradesOptions {
+    pub symbol: String,
+    /// Fetch trades older than this id (inverse contracts only)
+    pub from: Option<i64>,
+    /// Number of trades, up to 1000 (default 500)
+    pub limit: Option<i64>,
+}
+
+#[async_trait]
+pub trait FetchRecentTrades {
+    /// Fetch the recent public trades of a symbol, newest first.
+    /// The endpoint is chosen by the contract type of the symbol.
+    /// * `options` - The options for fetching the trades.
+    async fn fetch_recent_trades(
+        &self,
+        options: FetchRecentTradesOptions,
+    ) -> Result<Vec<PublicTrade>>;
+}
+
+impl Query for FetchRecentTradesOptions {}
+
+#[async_trait]
+impl FetchRecentTrades for Client {
+    async fn fetch_recent_trades(
+        &self,
+        options: FetchRecentTradesOptions,
+    ) -> Result<Vec<PublicTrade>> {
+        let path = if self.contract_type(&options.symbol).is_linear() {
+            "/public/linear/recent-trading-records"
+        } else {
+            "/v2/public/trading-records"
+        };
+        let response: Response<Vec<PublicTrade>> = self.get(path, &options).await?;
+        response.result()
+    }
+}
@@ -6,7 +6,7 @@ use crate::{
 use async_trait::async_trait;
 use serde::Serialize;
 
-#[derive(Default, Serialize)]
+#[derive(Clone, Default, Serialize)]
 pub struct FetchWalletFundRecordsOptions {
     pub start_date: Option<String>,
     pub end_date: Option<String>,
@@ -16,7 +16,7 @@ pub struct FetchWalletFundRecordsOptions {
     pub limit: Option<i64>,
 }
 
-#[derive(Default, Serialize)]
+#[derive(Clone, Default, Serialize)]
 pub struct FetchWalletWithdrawRecordsOptions {
     pub start_date: Option<String>,
     pub end_date: Option<String>,
@@ -26,13 +26,13 @@ pub struct FetchWalletWithdrawRecordsOptions {
     pub limit: Option<i64>,
 }
 
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
 pub enum Direction {
     Next,
     Prev,
 }
 
-#[derive(Default, Serialize)]
+#[derive(Clone, Default, Serialize)]
 pub struct FetchAssetExchangeRecordsOptions {
     pub limit: Option<i64>,
     pub from: Option<AssetExchangeRecordId>,
@@ -86,8 +86,8 @@ pub trait FetchAssetExchangeRecords {
 impl FetchWallets for Client {
     async fn fetch_wallets(&self) -> Result<Wallets> {
         let query = NoQuery::new();
-        let query = self.sign_query(query);
-        let response: Response<Wallets> = self.get("/v2/private/wallet/balance", &query).await?;
+        let response: Response<Wallets> =
+            self.signed_get("/v2/private/wallet/balance", query).await?;
         response.result()
     }
 }
@@ -98,8 +98,8 @@ impl FetchWallet for Client {
         let query = query::Wallet {
             coin: currency.to_owned(),
         };
-        let query = self.sign_query(query);
-        let response: Response<Wallets> = self.get("/v2/private/wallet/balance", &query).await?;
+        let response: Response<Wallets> =
+            self.signed_get("/v2/private/wallet/balance", query).await?;
         response.result().map(|res| res.get(currency).cloned())
     }
 }
@@ -110,9 +110,9 @@ impl FetchWalletFundRecords for Client {
         &self,
         options: FetchWalletFundRecordsOptions,
     ) -> Result<Vec<WalletFundRecord>> {
-        let query = self.sign_query(options);
-        let response: Response<response::WalletFundRecords> =
-            self.get("/v2/private/wallet/fund/records", &query).await?;
+        let response: Response<response::WalletFundRecords> = self
+            .signed_get("/v2/private/wallet/fund/records", options)
+            .await?;
         response.result().map(|res| res.data)
     }
 }
@@ -123,9 +123,9 @@ impl FetchWalletWithdrawRecords for Client {
         &self,
         options: FetchWalletWithdrawRecordsOptions,
     ) -> Result<Vec<WalletWithdrawRecord>> {
-        let query = self.sign_query(options);
-        let response: Response<response::WalletWithdrawRecords> =
-            self.get("/v2/private/wallet/withdraw/list", &query).await?;
+        let response: Response<response::WalletWithdrawRecords> = self
+            .signed_get("/v2/private/wallet/withdraw/list", options)
+            .await?;
         response.result().map(|res| res.data.unwrap_or_default())
     }
 }
@@ -136,9 +136,9 @@ impl FetchAssetExchangeRecords for Client {
         &self,
         options: FetchAssetExchangeRecordsOptions,
     ) -> Result<Vec<AssetExchangeRecord>> {
-        let query = self.sign_query(options);
-        let response: Response<Vec<AssetExchangeRecord>> =
-            self.get("/v2/private/exchange-order/list", &query).await?;
+        let response: Response<Vec<AssetExchangeRecord>> = self
+            .signed_get("/v2/private/exchange-order/list", options)
+            .await?;
         response.result()
     }
 }
@@ -151,7 +151,7 @@ mod query {
     use super::Query;
     use serde::Serialize;
 
-    #[derive(Serialize)]
+    #[derive(Clone, Serialize)]
     pub struct Wallet {
         pub coin: String,
     }
@@ -0,0 +1,67 @@
+use crate::deserialize::string_or_number;
+use serde::{Deserialize, Serialize};
+
+/// A risk limit tier of a symbol.
+/// Larger positions require a higher tier, which has higher margin requirements and a lower
+/// maximum leverage.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct RiskLimit {
+    /// Risk limit ID
+    pub id: i64,
+    /// Symbol
+    pub symbol: String,
+    /// Maximum position value of the tier (contracts for inverse contracts, quote currency for
+    /// linear contracts)
+    #[serde(deserialize_with = "string_or_number")]
+    pub limit: f64,
+    /// Maintenance margin rate
+    #[serde(deserialize_with = "string_or_number")]
+    pub maintain_margin: f64,
+    /// Initial margin rate
+    #[serde(deserialize_with = "string_or_number")]
+    pub starting_margin: f64,
+    /// Maximum leverage of the tier
+    #[serde(deserialize_with = "string_or_number")]
+    pub max_leverage: f64,
+}
+
+/// Returns the tier of the risk limits that covers a position, i.e. the tier with the lowest
+/// limit not below the position value. Returns `None` if the position exceeds every tier.
+/// * `risk_limits` - The risk limit tiers of the symbol.
+/// * `position_value` - The value of the position, in the unit of the limits.
+pub fn risk_limit_for<'a, I>(risk_limits: I, position_value: f64) -> Option<&'a RiskLimit>
+where
+    I: IntoIterator<Item = &'a RiskLimit>,
+{
+    risk_limits
+        .into_iter()
+        .filter(|risk_limit| risk_limit.limit >= position_value.abs())
+        .min_by(|a, b| a.limit.total_cmp(&b.limit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let json = r#"[
+            {"id":1,"symbol":"BTCUSD","limit":150,"maintain_margin":"0.5","starting_margin":"1",
+             "section":["1","2","3","5","10","25","50","100"],"is_lowest_risk":1,
+             "created_at":"2021-04-22T15:00:00Z","updated_at":"2021-04-22T15:00:00Z",
+             "max_leverage":"100","coin":"BTC"},
+            {"id":2,"symbol":"BTCUSD","limit":300,"maintain_margin":"1","starting_margin":"1.5",
+             "section":["1","2","3","5","10","25","50","66"],"is_lowest_risk":0,
+             "created_at":"2021-04-22T15:00:00Z","updated_at":"2021-04-22T15:00:00Z",
+             "max_leverage":"66.67","coin":"BTC"}
+        ]"#;
+        let risk_limits: Vec<RiskLimit> = serde_json::from_str(json).unwrap();
+        assert_eq!(risk_limits.len(), 2);
+        assert_eq!(risk_limits[1].max_leverage, 66.67);
+
+        assert_eq!(risk_limit_for(&risk_limits, 100.0).unwrap().id, 1);
+        assert_eq!(risk_limit_for(&risk_limits, 150.0).unwrap().id, 1);
+        assert_eq!(risk_limit_for(&risk_limits, -200.0).unwrap().id, 2);
+        assert!(risk_limit_for(&risk_limits, 301.0).is_none());
+    }
+}
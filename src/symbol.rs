@@ -1,4 +1,12 @@
-use crate::{contract::ContractStatus, filter::*};
+use crate::{
+    contract::{has_expiry, ContractStatus, ContractType},
+    decimal::decimal_from_f64,
+    filter::*,
+    rest::PlaceActiveOrderData,
+    risk_limit::risk_limit_for,
+    OrderType, Price, Qty, RiskLimit, ValidationError,
+};
+use rust_decimal::{Decimal, RoundingStrategy};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
@@ -45,6 +53,21 @@ impl Symbols {
     }
 }
 
+impl IntoIterator for Symbols {
+    type Item = Symbol;
+    type IntoIter = std::vec::IntoIter<Symbol>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl std::iter::FromIterator<Symbol> for Symbols {
+    fn from_iter<I: IntoIterator<Item = Symbol>>(iter: I) -> Self {
+        Symbols(iter.into_iter().collect())
+    }
+}
+
 impl Symbol {
     /// Create a symbol with the given name.
     /// * `name` - The name of the symbol.
@@ -54,6 +77,214 @@ impl Symbol {
             ..Default::default()
         }
     }
+
+    /// Returns the type of the contract, derived from the quote currency and the expiry in the
+    /// name or alias.
+    pub fn contract_type(&self) -> ContractType {
+        if self.quote_currency.is_empty() {
+            ContractType::from_name(&self.name)
+        } else if self.quote_currency == "USDT" {
+            ContractType::LinearPerpetual
+        } else if has_expiry(&self.name) || has_expiry(&self.alias) {
+            ContractType::InverseFutures
+        } else {
+            ContractType::InversePerpetual
+        }
+    }
+
+    /// Round a price to the nearest multiple of the tick size.
+    /// * `price` - The price to round.
+    pub fn round_price(&self, price: Price) -> Price {
+        snap(
+            price.value(),
+            self.price_filter.tick_size.value(),
+            |steps| steps.round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero),
+        )
+        .into()
+    }
+
+    /// Round a price down to a multiple of the tick size.
+    /// * `price` - The price to round.
+    pub fn floor_price(&self, price: Price) -> Price {
+        snap(
+            price.value(),
+            self.price_filter.tick_size.value(),
+            |steps| steps.floor(),
+        )
+        .into()
+    }
+
+    /// Round a price up to a multiple of the tick size.
+    /// * `price` - The price to round.
+    pub fn ceil_price(&self, price: Price) -> Price {
+        snap(
+            price.value(),
+            self.price_filter.tick_size.value(),
+            |steps| steps.ceil(),
+        )
+        .into()
+    }
+
+    /// Round a quantity down to a multiple of the quantity step,
+    /// so the order never exceeds the requested quantity.
+    /// * `qty` - The quantity to round.
+    pub fn snap_qty(&self, qty: Qty) -> Qty {
+        snap(
+            qty.value(),
+            self.lot_size_filter.qty_step.value(),
+            |steps| steps.floor(),
+        )
+        .into()
+    }
+
+    /// Validate a price against the price filter.
+    /// * `field` - The name of the field, used in the error.
+    /// * `price` - The price to validate.
+    pub fn validate_price(&self, field: &'static str, price: Price) -> Result<(), ValidationError> {
+        let filter = &self.price_filter;
+        if price < filter.min_price {
+            return Err(ValidationError::PriceTooLow {
+                field,
+                value: price,
+                min: filter.min_price,
+            });
+        }
+        if !filter.max_price.is_zero() && price > filter.max_price {
+            return Err(ValidationError::PriceTooHigh {
+                field,
+                value: price,
+                max: filter.max_price,
+            });
+        }
+        if !is_multiple(price.value(), filter.tick_size.value()) {
+            return Err(ValidationError::InvalidTickSize {
+                field,
+                value: price,
+                tick_size: filter.tick_size,
+            });
+        }
+        Ok(())
+    }
+
+    /// Validate a quantity against the lot size filter.
+    /// * `qty` - The quantity to validate.
+    pub fn validate_qty(&self, qty: Qty) -> Result<(), ValidationError> {
+        let filter = &self.lot_size_filter;
+        if qty.is_zero() || qty < filter.min_trading_qty {
+            return Err(ValidationError::QtyTooLow {
+                value: qty,
+                min: filter.min_trading_qty,
+            });
+        }
+        if !filter.max_trading_qty.is_zero() && qty > filter.max_trading_qty {
+            return Err(ValidationError::QtyTooHigh {
+                value: qty,
+                max: filter.max_trading_qty,
+            });
+        }
+        if !is_multiple(qty.value(), filter.qty_step.value()) {
+            return Err(ValidationError::InvalidQtyStep {
+                value: qty,
+                qty_step: filter.qty_step,
+            });
+        }
+        Ok(())
+    }
+
+    /// Validate a leverage against the leverage filter.
+    /// * `leverage` - The leverage to validate.
+    pub fn validate_leverage(&self, leverage: f64) -> Result<(), ValidationError> {
+        let filter = &self.leverage_filter;
+        if leverage < filter.min_leverage || leverage > filter.max_leverage {
+            return Err(ValidationError::LeverageOutOfRange {
+                value: leverage,
+                min: filter.min_leverage,
+                max: filter.max_leverage,
+            });
+        }
+        let step = filter.leverage_step.parse().unwrap_or_default();
+        let valid = decimal_from_f64(leverage).is_some_and(|leverage| is_multiple(leverage, step));
+        if !valid {
+            return Err(ValidationError::InvalidLeverageStep {
+                value: leverage,
+                leverage_step: filter.leverage_step.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns the maximum leverage allowed for a position of this symbol, the lowest of the
+    /// leverage filter and the maximum leverage of the risk limit tier covering the position.
+    /// Returns `None` if the position exceeds every risk limit tier.
+    /// * `risk_limits` - The risk limit tiers of this symbol.
+    /// * `position_value` - The value of the position, in the unit of the risk limits.
+    pub fn max_leverage(&self, risk_limits: &[RiskLimit], position_value: f64) -> Option<f64> {
+        let risk_limits = risk_limits
+            .iter()
+            .filter(|risk_limit| risk_limit.symbol == self.name);
+        let risk_limit = risk_limit_for(risk_limits, position_value)?;
+        Some(if self.leverage_filter.max_leverage > 0.0 {
+            risk_limit
+                .max_leverage
+                .min(self.leverage_filter.max_leverage)
+        } else {
+            risk_limit.max_leverage
+        })
+    }
+
+    /// Validate an order against the filters of this symbol.
+    /// * `data` - The order to validate.
+    /// * `leverage` - The leverage of the position the order is placed for, if any.
+    pub fn validate_order(
+        &self,
+        data: &PlaceActiveOrderData,
+        leverage: Option<f64>,
+    ) -> Result<(), ValidationError> {
+        if data.symbol != self.name {
+            return Err(ValidationError::SymbolMismatch {
+                expected: self.name.clone(),
+                actual: data.symbol.clone(),
+            });
+        }
+        if self.status != ContractStatus::Trading {
+            return Err(ValidationError::NotTrading(self.name.clone()));
+        }
+        self.validate_qty(data.qty)?;
+        match (data.order_type, data.price) {
+            (OrderType::Limit, None) => return Err(ValidationError::MissingPrice),
+            (OrderType::Limit, Some(price)) => self.validate_price("price", price)?,
+            (OrderType::Market, _) => {}
+        }
+        let prices = [
+            ("take_profit", data.take_profit),
+            ("stop_loss", data.stop_loss),
+            ("base_price", data.base_price),
+            ("stop_px", data.stop_px),
+        ];
+        for (field, price) in prices.iter() {
+            if let Some(price) = price {
+                self.validate_price(field, *price)?;
+            }
+        }
+        if let Some(leverage) = leverage {
+            self.validate_leverage(leverage)?;
+        }
+        Ok(())
+    }
+}
+
+/// Round `value` to a multiple of `step`, using `round` to round the number of steps.
+/// Returns `value` unchanged if `step` is zero.
+fn snap(value: Decimal, step: Decimal, round: impl Fn(Decimal) -> Decimal) -> Decimal {
+    if step.is_zero() {
+        return value;
+    }
+    (round(value / step) * step).normalize()
+}
+
+/// Returns `true` if `value` is a multiple of `step`, or if `step` is zero.
+fn is_multiple(value: Decimal, step: Decimal) -> bool {
+    step.is_zero() || (value % step).is_zero()
 }
 
 #[cfg(test)]
@@ -66,4 +297,163 @@ mod tests {
         assert!(symbols.get("LTC").is_none());
         assert!(symbols.get("ETH").is_some());
     }
+
+    fn btcusd() -> Symbol {
+        Symbol {
+            price_filter: PriceFilter {
+                min_price: "0.5".parse().unwrap(),
+                max_price: Price::from(999_999),
+                tick_size: "0.5".parse().unwrap(),
+            },
+            lot_size_filter: LotSizeFilter {
+                min_trading_qty: Qty::from(1),
+                max_trading_qty: Qty::from(1_000_000),
+                qty_step: Qty::from(1),
+            },
+            leverage_filter: LeverageFilter {
+                min_leverage: 1.0,
+                max_leverage: 100.0,
+                leverage_step: "0.01".to_owned(),
+            },
+            ..Symbol::new("BTCUSD")
+        }
+    }
+
+    #[test]
+    fn contract_type() {
+        let symbol = |name: &str, alias: &str, quote_currency: &str| Symbol {
+            alias: alias.to_owned(),
+            base_currency: "BTC".to_owned(),
+            quote_currency: quote_currency.to_owned(),
+            ..Symbol::new(name)
+        };
+        assert_eq!(
+            symbol("BTCUSD", "BTCUSD", "USD").contract_type(),
+            ContractType::InversePerpetual
+        );
+        assert_eq!(
+            symbol("BTCUSDT", "BTCUSDT", "USDT").contract_type(),
+            ContractType::LinearPerpetual
+        );
+        assert_eq!(
+            symbol("BTCUSDZ22", "BTCUSD1230", "USD").contract_type(),
+            ContractType::InverseFutures
+        );
+        assert_eq!(
+            symbol("BTCUSDM21", "BTCUSD0625", "USD").contract_type(),
+            ContractType::InverseFutures
+        );
+        assert_eq!(
+            Symbol::new("BTCUSDZ22").contract_type(),
+            ContractType::InverseFutures
+        );
+    }
+
+    #[test]
+    fn rounding() {
+        let symbol = btcusd();
+        let price: Price = "45420.3".parse().unwrap();
+        assert_eq!(symbol.round_price(price), "45420.5".parse().unwrap());
+        assert_eq!(symbol.floor_price(price), Price::from(45420));
+        assert_eq!(symbol.ceil_price(price), "45420.5".parse().unwrap());
+        assert_eq!(
+            symbol.round_price("45420.25".parse().unwrap()).to_string(),
+            "45420.5"
+        );
+        assert_eq!(symbol.snap_qty("10.9".parse().unwrap()), Qty::from(10));
+        assert_eq!(
+            Symbol::new("BTCUSD")
+                .snap_qty("10.9".parse().unwrap())
+                .to_string(),
+            "10.9"
+        );
+    }
+
+    #[test]
+    fn validate_order() {
+        let symbol = btcusd();
+        let order = PlaceActiveOrderData {
+            symbol: "BTCUSD".to_owned(),
+            qty: Qty::from(10),
+            order_type: OrderType::Limit,
+            price: Some("45420.5".parse().unwrap()),
+            ..Default::default()
+        };
+        assert_eq!(symbol.validate_order(&order, Some(2.5)), Ok(()));
+
+        let invalid = PlaceActiveOrderData {
+            price: Some("45420.2".parse().unwrap()),
+            ..order
+        };
+        assert!(matches!(
+            symbol.validate_order(&invalid, None),
+            Err(ValidationError::InvalidTickSize { field: "price", .. })
+        ));
+        let invalid = PlaceActiveOrderData {
+            price: None,
+            ..invalid
+        };
+        assert_eq!(
+            symbol.validate_order(&invalid, None),
+            Err(ValidationError::MissingPrice)
+        );
+        let invalid = PlaceActiveOrderData {
+            order_type: OrderType::Market,
+            qty: "0.5".parse().unwrap(),
+            ..invalid
+        };
+        assert!(matches!(
+            symbol.validate_order(&invalid, None),
+            Err(ValidationError::QtyTooLow { .. })
+        ));
+        let invalid = PlaceActiveOrderData {
+            qty: Qty::from(1),
+            stop_loss: Some(Price::ZERO),
+            ..invalid
+        };
+        assert!(matches!(
+            symbol.validate_order(&invalid, None),
+            Err(ValidationError::PriceTooLow {
+                field: "stop_loss",
+                ..
+            })
+        ));
+        let valid = PlaceActiveOrderData {
+            stop_loss: None,
+            ..invalid
+        };
+        assert!(symbol.validate_order(&valid, None).is_ok());
+        assert!(matches!(
+            symbol.validate_order(&valid, Some(150.0)),
+            Err(ValidationError::LeverageOutOfRange { .. })
+        ));
+        assert!(matches!(
+            symbol.validate_order(&valid, Some(2.005)),
+            Err(ValidationError::InvalidLeverageStep { .. })
+        ));
+    }
+
+    #[test]
+    fn max_leverage() {
+        let risk_limit = |id, limit, max_leverage| RiskLimit {
+            id,
+            symbol: "BTCUSD".to_owned(),
+            limit,
+            maintain_margin: 0.5,
+            starting_margin: 1.0,
+            max_leverage,
+        };
+        let risk_limits = vec![risk_limit(1, 150.0, 100.0), risk_limit(2, 300.0, 66.67)];
+        let mut symbol = btcusd();
+        assert_eq!(symbol.max_leverage(&risk_limits, 100.0), Some(100.0));
+        assert_eq!(symbol.max_leverage(&risk_limits, 200.0), Some(66.67));
+        assert_eq!(symbol.max_leverage(&risk_limits, 500.0), None);
+
+        symbol.leverage_filter.max_leverage = 50.0;
+        assert_eq!(symbol.max_leverage(&risk_limits, 100.0), Some(50.0));
+        assert_eq!(
+            Symbol::new("ETHUSD").max_leverage(&risk_limits, 100.0),
+            None
+        );
+    }
 }
@@ -0,0 +1,261 @@
+use crate::{
+    contract::ContractStatus,
+    http::{self, Client},
+    rest::FetchSymbols,
+    Symbol, Symbols,
+};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use tokio::time;
+
+/// Capacity of the status change channel, slow subscribers miss the oldest changes.
+const STATUS_CHANNEL_CAPACITY: usize = 64;
+
+/// A change of the `ContractStatus` of a symbol, detected while refreshing a `SymbolRegistry`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolStatusChange {
+    /// Symbol name
+    pub name: String,
+    /// Status before the refresh
+    pub previous: ContractStatus,
+    /// Status after the refresh
+    pub status: ContractStatus,
+}
+
+/// A shared cache of symbols, indexed by name and alias.
+/// Clones share the same cache, so a single registry can be used by multiple clients.
+#[derive(Debug, Clone)]
+pub struct SymbolRegistry {
+    index: Arc<RwLock<Index>>,
+    changes: broadcast::Sender<SymbolStatusChange>,
+}
+
+#[derive(Debug, Default)]
+struct Index {
+    symbols: HashMap<String, Arc<Symbol>>,
+    aliases: HashMap<String, String>,
+    loaded: bool,
+}
+
+impl Default for SymbolRegistry {
+    fn default() -> Self {
+        let (changes, _) = broadcast::channel(STATUS_CHANNEL_CAPACITY);
+        SymbolRegistry {
+            index: Default::default(),
+            changes,
+        }
+    }
+}
+
+impl SymbolRegistry {
+    /// Create a new, empty registry.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Create a registry and load the symbols.
+    /// * `client` - The client used to fetch the symbols.
+    pub async fn load(client: &Client) -> http::Result<Self> {
+        let registry = SymbolRegistry::new();
+        registry.refresh(client).await?;
+        Ok(registry)
+    }
+
+    /// Fetch the symbols and update the registry.
+    /// Returns the status changes, which are also sent to the subscribers.
+    /// * `client` - The client used to fetch the symbols.
+    pub async fn refresh(&self, client: &Client) -> http::Result<Vec<SymbolStatusChange>> {
+        let symbols = client.fetch_symbols().await?;
+        Ok(self.update(symbols))
+    }
+
+    /// Replace the symbols of the registry.
+    /// Returns the status changes, which are also sent to the subscribers.
+    /// * `symbols` - The symbols.
+    pub fn update(&self, symbols: Symbols) -> Vec<SymbolStatusChange> {
+        let mut changes = Vec::new();
+        {
+            let mut index = self.index.write().unwrap();
+            let mut updated = Index {
+                loaded: true,
+                ..Default::default()
+            };
+            for symbol in symbols {
+                if let Some(previous) = index.symbols.get(&symbol.name) {
+                    if previous.status != symbol.status {
+                        changes.push(SymbolStatusChange {
+                            name: symbol.name.clone(),
+                            previous: previous.status,
+                            status: symbol.status,
+                        });
+                    }
+                }
+                if !symbol.alias.is_empty() && symbol.alias != symbol.name {
+                    updated
+                        .aliases
+                        .insert(symbol.alias.clone(), symbol.name.clone());
+                }
+                updated
+                    .symbols
+                    .insert(symbol.name.clone(), Arc::new(symbol));
+            }
+            *index = updated;
+        }
+        for change in changes.iter() {
+            // Sending only fails if there are no subscribers.
+            let _res = self.changes.send(change.clone());
+        }
+        changes
+    }
+
+    /// Returns `true` if the symbols have been loaded.
+    pub fn is_loaded(&self) -> bool {
+        self.index.read().unwrap().loaded
+    }
+
+    /// Get the symbol with the given name or alias.
+    /// * `name` - The name or alias of the symbol.
+    pub fn get(&self, name: &str) -> Option<Arc<Symbol>> {
+        let index = self.index.read().unwrap();
+        let name = index.aliases.get(name).map(String::as_str).unwrap_or(name);
+        index.symbols.get(name).cloned()
+    }
+
+    /// Returns all symbols of the registry.
+    pub fn symbols(&self) -> Vec<Arc<Symbol>> {
+        self.index
+            .read()
+            .unwrap()
+            .symbols
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    /// Subscribe to the status changes of the symbols.
+    pub fn subscribe(&self) -> broadcast::Receiver<SymbolStatusChange> {
+        self.changes.subscribe()
+    }
+
+    /// Spawn a task that refreshes this registry on the given interval.
+    /// Failed refreshes keep the previous symbols.
+    /// * `client` - The client used to fetch the symbols.
+    /// * `interval` - The interval between two refreshes.
+    pub fn spawn_refresh(&self, client: Client, interval: std::time::Duration) -> JoinHandle<()> {
+        let registry = self.clone();
+        tokio::spawn(async move {
+            let mut interval = time::interval(interval);
+            loop {
+                interval.tick().await;
+                let _res = registry.refresh(&client).await;
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::{PlaceActiveOrder, PlaceActiveOrderData};
+    use crate::testing::{MockResponse, MockServer};
+    use crate::{OrderType, Price, Qty, ValidationError};
+    use serde_json::json;
+
+    fn symbols(status: ContractStatus) -> Symbols {
+        let future = Symbol {
+            alias: "BTCUSD0625".to_owned(),
+            status,
+            ..Symbol::new("BTCUSDM21")
+        };
+        vec![Symbol::new("BTCUSD"), future].into_iter().collect()
+    }
+
+    #[test]
+    fn index() {
+        let registry = SymbolRegistry::new();
+        assert!(!registry.is_loaded());
+        assert!(registry.get("BTCUSD").is_none());
+
+        registry.update(symbols(ContractStatus::Trading));
+        assert!(registry.is_loaded());
+        assert!(registry.get("BTCUSD").is_some());
+        assert_eq!(registry.get("BTCUSD0625").unwrap().name, "BTCUSDM21");
+        assert!(registry.get("BTCUSDM21").is_some());
+        assert_eq!(registry.symbols().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn status_changes() {
+        let registry = SymbolRegistry::new();
+        let mut changes = registry.subscribe();
+        assert!(registry.update(symbols(ContractStatus::Trading)).is_empty());
+        assert!(registry.update(symbols(ContractStatus::Trading)).is_empty());
+
+        registry.update(symbols(ContractStatus::Settling));
+        let change = changes.recv().await.unwrap();
+        assert_eq!(
+            change,
+            SymbolStatusChange {
+                name: "BTCUSDM21".to_owned(),
+                previous: ContractStatus::Trading,
+                status: ContractStatus::Settling,
+            }
+        );
+        assert_eq!(
+            registry.get("BTCUSD0625").unwrap().status,
+            ContractStatus::Settling
+        );
+    }
+
+    #[tokio::test]
+    async fn order_validation() {
+        let server = MockServer::start().await.unwrap();
+        server.set_response(
+            "/v2/public/symbols",
+            MockResponse::ok(json!([{
+                "name": "BTCUSD",
+                "alias": "BTCUSD",
+                "status": "Trading",
+                "base_currency": "BTC",
+                "quote_currency": "USD",
+                "price_scale": 2,
+                "taker_fee": "0.00075",
+                "maker_fee": "-0.00025",
+                "leverage_filter": {"min_leverage": 1, "max_leverage": 100, "leverage_step": "0.01"},
+                "price_filter": {"min_price": "0.5", "max_price": "999999.5", "tick_size": "0.5"},
+                "lot_size_filter": {"min_trading_qty": 1, "max_trading_qty": 1000000, "qty_step": 1}
+            }])),
+        );
+        let mut client = server.http_client();
+        let registry = SymbolRegistry::load(&client).await.unwrap();
+        client.set_symbol_registry(Some(registry));
+
+        let data = PlaceActiveOrderData {
+            symbol: "BTCUSD".to_owned(),
+            qty: Qty::from(1),
+            order_type: OrderType::Limit,
+            price: Some("8800.25".parse().unwrap()),
+            ..Default::default()
+        };
+        let err = client.place_active_order(data).await.unwrap_err();
+        assert!(matches!(
+            err,
+            http::Error::Validation(ValidationError::InvalidTickSize { .. })
+        ));
+        assert!(!server
+            .requests()
+            .iter()
+            .any(|request| request.path == "/v2/private/order/create"));
+
+        let data = PlaceActiveOrderData {
+            symbol: "BTCUSD".to_owned(),
+            qty: Qty::from(1),
+            order_type: OrderType::Limit,
+            price: Some(Price::from(8800)),
+            ..Default::default()
+        };
+        assert!(client.place_active_order(data).await.is_ok());
+    }
+}
@@ -0,0 +1,572 @@
+//! An offline mock of the Bybit v2 REST and realtime APIs.
+//!
+//! The mock server listens on localhost, emulates the endpoints used by the `rest` traits and the
+//! topics of `ws::Channel`, and verifies signatures the way Bybit does.
+//! Responses can be scripted per path, anything else falls back to a minimal emulation.
+mod realtime;
+mod response;
+mod rest;
+pub use response::*;
+
+use crate::{http, ws};
+use chrono::Utc;
+use serde_json::{Map, Value};
+use std::collections::{HashMap, VecDeque};
+use std::io;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use tokio::net::TcpListener;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+
+/// The api key accepted by a mock server created with `MockServer::start`.
+pub const API_KEY: &str = "mock-api-key";
+/// The api secret accepted by a mock server created with `MockServer::start`.
+pub const API_SECRET: &str = "mock-api-secret";
+
+/// A local server emulating the Bybit REST and realtime APIs.
+/// The server stops when it is dropped.
+pub struct MockServer {
+    http_addr: SocketAddr,
+    ws_addr: SocketAddr,
+    state: Arc<Mutex<State>>,
+    events: broadcast::Sender<realtime::Event>,
+    tasks: Vec<JoinHandle<()>>,
+}
+
+struct State {
+    api_key: String,
+    api_secret: String,
+    time_offset_ms: i64,
+    scripted: HashMap<String, VecDeque<MockResponse>>,
+    fixed: HashMap<String, MockResponse>,
+    requests: Vec<MockRequest>,
+    orders: Vec<Map<String, Value>>,
+    next_order_id: u64,
+}
+
+impl State {
+    /// Returns the current time (in milliseconds) of the server clock.
+    fn now_ms(&self) -> i64 {
+        Utc::now().timestamp_millis() + self.time_offset_ms
+    }
+
+    /// Returns the current time of the server clock, formatted as `time_now`.
+    fn time_now(&self) -> String {
+        let now_ms = self.now_ms();
+        format!(
+            "{}.{:03}000",
+            now_ms.div_euclid(1000),
+            now_ms.rem_euclid(1000)
+        )
+    }
+}
+
+impl MockServer {
+    /// Start a mock server accepting `API_KEY` and `API_SECRET`.
+    pub async fn start() -> io::Result<Self> {
+        MockServer::with_credentials(API_KEY, API_SECRET).await
+    }
+
+    /// Start a mock server accepting the given credentials.
+    /// * `api_key` - The accepted api key.
+    /// * `api_secret` - The secret used to verify signatures.
+    pub async fn with_credentials(api_key: &str, api_secret: &str) -> io::Result<Self> {
+        let state = Arc::new(Mutex::new(State {
+            api_key: api_key.to_owned(),
+            api_secret: api_secret.to_owned(),
+            time_offset_ms: 0,
+            scripted: HashMap::new(),
+            fixed: HashMap::new(),
+            requests: Vec::new(),
+            orders: Vec::new(),
+            next_order_id: 1,
+        }));
+        let (events, _) = broadcast::channel(1024);
+
+        let http_listener = TcpListener::bind("127.0.0.1:0").await?;
+        let ws_listener = TcpListener::bind("127.0.0.1:0").await?;
+        let http_addr = http_listener.local_addr()?;
+        let ws_addr = ws_listener.local_addr()?;
+
+        let tasks = vec![
+            tokio::spawn(rest::serve(http_listener, state.clone())),
+            tokio::spawn(realtime::serve(ws_listener, state.clone(), events.clone())),
+        ];
+
+        Ok(MockServer {
+            http_addr,
+            ws_addr,
+            state,
+            events,
+            tasks,
+        })
+    }
+
+    /// Returns the base url of the REST API.
+    pub fn http_url(&self) -> String {
+        format!("http://{}/", self.http_addr)
+    }
+
+    /// Returns the url of the realtime API.
+    pub fn ws_url(&self) -> String {
+        format!("ws://{}/realtime", self.ws_addr)
+    }
+
+    /// Create a REST client connected to this server, using the accepted credentials.
+    pub fn http_client(&self) -> http::Client {
+        let state = self.state.lock().unwrap();
+        http::Client::new(&self.http_url(), &state.api_key, &state.api_secret).unwrap()
+    }
+
+    /// Create a websocket client for this server, using the accepted credentials.
+    pub fn ws_client(&self) -> ws::Client {
+        let state = self.state.lock().unwrap();
+        ws::Client::new(&self.ws_url(), &state.api_key, &state.api_secret)
+    }
+
+    /// Queue a response for the next request to the given path.
+    /// Queued responses are used once, in order, before any response set with `set_response`.
+    /// * `path` - The path of the endpoint, e.g. `/v2/private/order/create`.
+    /// * `response` - The response to send.
+    pub fn enqueue(&self, path: &str, response: MockResponse) {
+        let mut state = self.state.lock().unwrap();
+        state
+            .scripted
+            .entry(normalize_path(path))
+            .or_default()
+            .push_back(response);
+    }
+
+    /// Respond to every request to the given path with the given response.
+    /// * `path` - The path of the endpoint, e.g. `/v2/public/symbols`.
+    /// * `response` - The response to send.
+    pub fn set_response(&self, path: &str, response: MockResponse) {
+        let mut state = self.state.lock().unwrap();
+        state.fixed.insert(normalize_path(path), response);
+    }
+
+    /// Remove all scripted responses, falling back to the emulated endpoints.
+    pub fn clear_responses(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.scripted.clear();
+        state.fixed.clear();
+    }
+
+    /// Returns all REST requests received so far.
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    /// Set the offset (in milliseconds) of the server clock relative to the local clock.
+    /// * `offset_ms` - The offset.
+    pub fn set_time_offset_ms(&self, offset_ms: i64) {
+        self.state.lock().unwrap().time_offset_ms = offset_ms;
+    }
+
+    /// Publish a message to every websocket connection subscribed to the given topic.
+    /// * `topic` - The topic, e.g. `trade` or `orderBookL2_25.BTCUSD`.
+    /// * `message` - The message to send.
+    pub fn publish(&self, topic: &str, message: Value) {
+        let _res = self.events.send(realtime::Event::Publish {
+            topic: topic.to_owned(),
+            text: message.to_string(),
+        });
+    }
+
+    /// Close every websocket connection.
+    pub fn disconnect_all(&self) {
+        let _res = self.events.send(realtime::Event::Disconnect);
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        for task in self.tasks.iter() {
+            task.abort();
+        }
+    }
+}
+
+fn normalize_path(path: &str) -> String {
+    format!("/{}", path.trim_start_matches('/'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::{BybitErrorKind, RetryPolicy};
+    use crate::rest::{
+        ActiveOrderId, CancelConditionalOrders, CancelOrders, ChangePositionMargin,
+        FetchApiKeyInfo, FetchFundingFee, FetchFundingRate, FetchPredictedFunding, FetchSymbols,
+        FetchWallets, ListActiveLinearOrders, ListActiveOrders, ListActiveOrdersFilter,
+        ListConditionalOrders, ListConditionalOrdersFilter, ListPositions, PlaceActiveLinearOrder,
+        PlaceActiveOrder, PlaceActiveOrderData, PlaceConditionalOrder, PlaceLinearConditionalOrder,
+        PlaceLinearConditionalOrderData, QueryActiveLinearOrder, QueryActiveOrder,
+        QueryConditionalOrder, ServerTime, SetLeverage, SetRiskLimit, UpdateConditionalOrderData,
+        UpdateConditionalOrders, UpdateOrderData, UpdateOrders,
+    };
+    use crate::{
+        ApiKeyError, ApiKeyPermission, OrderLinkId, OrderStatus, OrderType, Price, Qty, Side,
+        StopOrderStatus,
+    };
+    use serde_json::json;
+    use std::time::Duration;
+
+    fn order_data(order_link_id: Option<&str>) -> PlaceActiveOrderData {
+        PlaceActiveOrderData {
+            symbol: "BTCUSD".to_owned(),
+            side: Side::Buy,
+            qty: Qty::from(1),
+            order_type: OrderType::Limit,
+            price: Some(Price::from(8800)),
+            order_link_id: order_link_id.map(|id| serde_json::from_value(json!(id)).unwrap()),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn public_endpoints() {
+        let server = MockServer::start().await.unwrap();
+        let client = server.http_client();
+        assert!(client.server_time().await.is_ok());
+        assert!(client
+            .fetch_symbols()
+            .await
+            .unwrap()
+            .get("BTCUSD")
+            .is_none());
+
+        server.set_response(
+            "/v2/public/time",
+            MockResponse::error(BybitErrorKind::SystemBusy.code(), "system busy"),
+        );
+        let err = client.server_time().await.unwrap_err();
+        assert_eq!(err.kind(), Some(BybitErrorKind::SystemBusy));
+    }
+
+    #[tokio::test]
+    async fn signature_verification() {
+        let server = MockServer::start().await.unwrap();
+        assert!(server.http_client().fetch_wallets().await.is_ok());
+
+        let client = http::Client::new(&server.http_url(), API_KEY, "wrong-secret").unwrap();
+        let err = client.fetch_wallets().await.unwrap_err();
+        assert_eq!(err.kind(), Some(BybitErrorKind::InvalidSign));
+
+        let client = http::Client::new(&server.http_url(), "wrong-key", API_SECRET).unwrap();
+        let err = client.fetch_wallets().await.unwrap_err();
+        assert_eq!(err.kind(), Some(BybitErrorKind::InvalidApiKey));
+
+        let request = server.requests().pop().unwrap();
+        assert_eq!(request.path, "/v2/private/wallet/balance");
+        assert_eq!(request.param("api_key"), Some("wrong-key"));
+    }
+
+    #[tokio::test]
+    async fn clock_offset() {
+        let server = MockServer::start().await.unwrap();
+        server.set_time_offset_ms(-60_000);
+        let client = server.http_client();
+        let err = client.fetch_wallets().await.unwrap_err();
+        assert_eq!(err.kind(), Some(BybitErrorKind::RequestExpired));
+
+        let offset = client.sync_clock().await.unwrap();
+        assert!((-61_000..=-59_000).contains(&offset));
+        assert!(client.fetch_wallets().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn order_lifecycle() {
+        let server = MockServer::start().await.unwrap();
+        let client = server.http_client();
+
+        let order = client.place_active_order(order_data(None)).await.unwrap();
+        assert_eq!(order.order_status, OrderStatus::New);
+        assert_eq!(order.price, Price::from(8800));
+
+        let filter = ListActiveOrdersFilter {
+            symbol: "BTCUSD".to_owned(),
+            ..Default::default()
+        };
+        assert_eq!(client.list_orders(filter).await.unwrap().len(), 1);
+
+        let id = ActiveOrderId::from(order.id.clone());
+        client
+            .cancel_active_order(id.clone(), "BTCUSD")
+            .await
+            .unwrap();
+        let err = client
+            .cancel_active_order(id.clone(), "BTCUSD")
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), Some(BybitErrorKind::OrderFilledOrCanceled));
+
+        let order = client.query_active_order(id, "BTCUSD").await.unwrap();
+        assert_eq!(order.unwrap().order_status, OrderStatus::Cancelled);
+
+        let link_id: OrderLinkId = serde_json::from_value(json!("missing")).unwrap();
+        let order = client
+            .query_active_order(link_id.into(), "BTCUSD")
+            .await
+            .unwrap();
+        assert!(order.is_none());
+    }
+
+    #[tokio::test]
+    async fn positions() {
+        let server = MockServer::start().await.unwrap();
+        let client = server.http_client();
+
+        let positions = client.list_positions(Some("BTCUSD")).await.unwrap();
+        assert_eq!(positions.len(), 1);
+        assert_eq!(positions[0].side, None);
+        let positions = client.list_positions(Some("BTCUSDT")).await.unwrap();
+        assert_eq!(positions.len(), 2);
+        assert_eq!(positions[1].side, Some(Side::Sell));
+
+        client.set_leverage("BTCUSD", 10.0).await.unwrap();
+        let request = server.requests().pop().unwrap();
+        assert_eq!(request.path, "/v2/private/position/leverage/save");
+        assert_eq!(request.param("leverage"), Some("10.0"));
+
+        client.set_leverage("BTCUSDT", 5.0).await.unwrap();
+        let request = server.requests().pop().unwrap();
+        assert_eq!(request.path, "/private/linear/position/set-leverage");
+        assert_eq!(request.param("buy_leverage"), Some("5.0"));
+        assert_eq!(request.param("sell_leverage"), Some("5.0"));
+
+        client
+            .change_position_margin("BTCUSDT", Some(Side::Buy), "0.5".parse().unwrap())
+            .await
+            .unwrap();
+        let request = server.requests().pop().unwrap();
+        assert_eq!(request.path, "/private/linear/position/add-margin");
+        assert_eq!(request.param("side"), Some("Buy"));
+        assert_eq!(request.param("margin"), Some("0.5"));
+
+        // Changing the margin is never retried.
+        let path = "/v2/private/position/change-position-margin";
+        server.enqueue(path, MockResponse::status(503));
+        assert!(client
+            .change_position_margin("BTCUSD", None, Qty::from(1))
+            .await
+            .is_err());
+        let changes = server
+            .requests()
+            .iter()
+            .filter(|request| request.path == path)
+            .count();
+        assert_eq!(changes, 1);
+
+        client
+            .set_risk_limit("BTCUSDT", Some(Side::Sell), 2)
+            .await
+            .unwrap();
+        let request = server.requests().pop().unwrap();
+        assert_eq!(request.path, "/private/linear/position/set-risk");
+        assert_eq!(request.param("side"), Some("Sell"));
+    }
+
+    #[tokio::test]
+    async fn funding() {
+        let server = MockServer::start().await.unwrap();
+        let client = server.http_client();
+
+        let funding_rate = client.fetch_funding_rate("BTCUSDT").await.unwrap();
+        let request = server.requests().pop().unwrap();
+        assert_eq!(request.path, "/public/linear/funding/prev-funding-rate");
+        assert_eq!(funding_rate.funding_rate, 0.0001);
+        assert_eq!(funding_rate.funding_rate_timestamp % (8 * 3600), 0);
+        let funding_fee = client.fetch_funding_fee("BTCUSD").await.unwrap();
+        assert_eq!(
+            funding_fee.exec_timestamp,
+            funding_rate.funding_rate_timestamp
+        );
+        let predicted = client.fetch_predicted_funding("BTCUSD").await.unwrap();
+        assert_eq!(predicted.predicted_funding_rate, 0.0001);
+    }
+
+    #[tokio::test]
+    async fn api_key() {
+        let server = MockServer::start().await.unwrap();
+        let client = server.http_client();
+
+        let permissions = [ApiKeyPermission::Order, ApiKeyPermission::Position];
+        let info = client.verify_api_key(&permissions).await.unwrap();
+        assert_eq!(info.api_key, API_KEY);
+        let err = client
+            .verify_api_key(&[ApiKeyPermission::Order])
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            http::Error::ApiKey(ApiKeyError::UnexpectedPermission(
+                ApiKeyPermission::Position
+            ))
+        ));
+
+        let client = http::Client::new(&server.http_url(), "other", API_SECRET).unwrap();
+        assert!(client.verify_api_key(&permissions).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn linear_orders() {
+        let server = MockServer::start().await.unwrap();
+        let client = server.http_client();
+
+        let data = PlaceActiveOrderData {
+            symbol: "BTCUSDT".to_owned(),
+            qty: "0.01".parse().unwrap(),
+            order_type: OrderType::Limit,
+            price: Some(Price::from(8000)),
+            ..Default::default()
+        };
+        let placed = client.place_active_linear_order(data).await.unwrap();
+        let filter = ListActiveOrdersFilter {
+            symbol: "BTCUSDT".to_owned(),
+            ..Default::default()
+        };
+        let orders = client.list_linear_orders(filter).await.unwrap();
+        assert_eq!(orders.len(), 1);
+
+        let data = UpdateOrderData {
+            symbol: "BTCUSDT".to_owned(),
+            price: Price::from(8100),
+        };
+        let id = client
+            .update_order(placed.id.clone().into(), data)
+            .await
+            .unwrap();
+        let order = client
+            .query_active_linear_order(id.clone().into(), "BTCUSDT")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(order.price, Price::from(8100));
+
+        client
+            .cancel_active_order(id.clone().into(), "BTCUSDT")
+            .await
+            .unwrap();
+        let order = client
+            .query_active_linear_order(id.into(), "BTCUSDT")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(order.order_status, OrderStatus::Cancelled);
+        assert!(client
+            .cancel_all_active_orders("BTCUSDT")
+            .await
+            .unwrap()
+            .is_empty());
+
+        let paths: Vec<String> = server.requests().into_iter().map(|r| r.path).collect();
+        assert!(paths.iter().all(|path| !path.starts_with("/v2/")));
+    }
+
+    #[tokio::test]
+    async fn conditional_orders() {
+        let server = MockServer::start().await.unwrap();
+        let client = server.http_client();
+
+        let data = PlaceActiveOrderData {
+            symbol: "BTCUSD".to_owned(),
+            qty: Qty::from(1),
+            order_type: OrderType::Limit,
+            price: Some(Price::from(8000)),
+            base_price: Some(Price::from(7000)),
+            stop_px: Some(Price::from(7500)),
+            order_link_id: Some(serde_json::from_value(json!("stop-inverse")).unwrap()),
+            ..Default::default()
+        };
+        let inverse = client.place_conditional_order(data).await.unwrap();
+        let data = PlaceLinearConditionalOrderData {
+            symbol: "BTCUSDT".to_owned(),
+            qty: "0.001".parse().unwrap(),
+            order_type: OrderType::Limit,
+            price: Some(Price::from(8000)),
+            base_price: Price::from(7000),
+            stop_px: Price::from(7500),
+            order_link_id: Some(serde_json::from_value(json!("stop-linear")).unwrap()),
+            ..Default::default()
+        };
+        let linear = client.place_linear_conditional_order(data).await.unwrap();
+
+        for (symbol, placed) in [("BTCUSD", inverse), ("BTCUSDT", linear)].iter() {
+            assert_eq!(placed.order_status, StopOrderStatus::Untriggered);
+            let filter = ListConditionalOrdersFilter {
+                symbol: symbol.to_string(),
+                ..Default::default()
+            };
+            let orders = client.list_conditional_orders(filter).await.unwrap();
+            assert_eq!(orders.len(), 1);
+
+            let data = UpdateConditionalOrderData {
+                symbol: symbol.to_string(),
+                trigger_price: Some(Price::from(7600)),
+                ..Default::default()
+            };
+            let id = client
+                .update_conditional_order(placed.link_id.clone().into(), data)
+                .await
+                .unwrap();
+            assert_eq!(id, placed.id);
+            let order = client
+                .query_conditional_order(id.clone().into(), symbol)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(order.stop_px, Some(Price::from(7600)));
+
+            let cancelled = client.cancel_all_conditional_orders(symbol).await.unwrap();
+            assert_eq!(cancelled, vec![id.clone()]);
+            let order = client
+                .query_conditional_order(id.clone().into(), symbol)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(order.order_status, StopOrderStatus::Deactivated);
+            assert!(client
+                .cancel_conditional_order(id.into(), symbol)
+                .await
+                .is_err());
+        }
+        let paths: Vec<String> = server.requests().into_iter().map(|r| r.path).collect();
+        assert!(paths
+            .iter()
+            .any(|path| path == "/private/linear/stop-order/cancel-all"));
+        assert!(paths
+            .iter()
+            .any(|path| path == "/v2/private/stop-order/cancelAll"));
+    }
+
+    #[tokio::test]
+    async fn scripted_retries() {
+        let server = MockServer::start().await.unwrap();
+        let mut client = server.http_client();
+        client.set_retry_policy(RetryPolicy {
+            initial_backoff: Duration::from_millis(1),
+            ..Default::default()
+        });
+
+        let path = "/v2/private/order/create";
+        server.enqueue(path, MockResponse::status(503));
+        server.enqueue(
+            path,
+            MockResponse::error(BybitErrorKind::SystemBusy.code(), "system busy"),
+        );
+        let order = client
+            .place_active_order(order_data(Some("retried")))
+            .await
+            .unwrap();
+        assert_eq!(order.link_id.to_string(), "retried");
+
+        let creates = server
+            .requests()
+            .iter()
+            .filter(|request| request.path == path)
+            .count();
+        assert_eq!(creates, 3);
+    }
+}
@@ -0,0 +1,162 @@
+use super::State;
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+use tokio_tungstenite::tungstenite::Message;
+
+/// Topics that require an authenticated connection.
+const PRIVATE_TOPICS: [&str; 4] = ["position", "execution", "order", "stop_order"];
+
+#[derive(Clone, Debug)]
+pub(super) enum Event {
+    /// Send `text` to every connection subscribed to `topic`.
+    Publish { topic: String, text: String },
+    /// Close every connection.
+    Disconnect,
+}
+
+/// Accept and serve websocket connections until the task is aborted.
+pub(super) async fn serve(
+    listener: TcpListener,
+    state: Arc<Mutex<State>>,
+    events: broadcast::Sender<Event>,
+) {
+    let mut conn_id = 0_u64;
+    while let Ok((stream, _)) = listener.accept().await {
+        conn_id += 1;
+        let session = Session {
+            conn_id: format!("mock-conn-{}", conn_id),
+            authenticated: false,
+            topics: HashSet::new(),
+        };
+        tokio::spawn(session.run(stream, state.clone(), events.subscribe()));
+    }
+}
+
+struct Session {
+    conn_id: String,
+    authenticated: bool,
+    topics: HashSet<String>,
+}
+
+impl Session {
+    async fn run(
+        mut self,
+        stream: TcpStream,
+        state: Arc<Mutex<State>>,
+        mut events: broadcast::Receiver<Event>,
+    ) {
+        let mut query = String::new();
+        // The error type is dictated by tungstenite.
+        #[allow(clippy::result_large_err)]
+        let callback = |request: &Request, response: Response| {
+            query = request.uri().query().unwrap_or_default().to_owned();
+            Ok(response)
+        };
+        let mut stream = match tokio_tungstenite::accept_hdr_async(stream, callback).await {
+            Ok(stream) => stream,
+            Err(_) => return,
+        };
+        self.authenticated = authenticate(&state.lock().unwrap(), &query);
+
+        loop {
+            tokio::select! {
+                msg = stream.next() => match msg {
+                    Some(Ok(Message::Text(text))) => {
+                        if let Some(reply) = self.handle(&text) {
+                            if stream.send(Message::Text(reply)).await.is_err() {
+                                return;
+                            }
+                        }
+                    }
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => return,
+                    Some(Ok(_)) => {}
+                },
+                event = events.recv() => match event {
+                    Ok(Event::Publish { topic, text }) if self.topics.contains(&topic) => {
+                        if stream.send(Message::Text(text)).await.is_err() {
+                            return;
+                        }
+                    }
+                    Ok(Event::Publish { .. }) | Err(RecvError::Lagged(_)) => {}
+                    Ok(Event::Disconnect) | Err(RecvError::Closed) => {
+                        let _res = stream.close(None).await;
+                        return;
+                    }
+                },
+            }
+        }
+    }
+
+    /// Handle a request of the client, returning the reply.
+    fn handle(&mut self, text: &str) -> Option<String> {
+        let request: Value = serde_json::from_str(text).ok()?;
+        let op = request["op"].as_str()?;
+        let (success, ret_msg) = match op {
+            "ping" => (true, "pong"),
+            "subscribe" | "unsubscribe" => {
+                let topics: Vec<String> = request["args"]
+                    .as_array()
+                    .map(|args| {
+                        args.iter()
+                            .filter_map(|arg| arg.as_str().map(str::to_owned))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                let private = topics
+                    .iter()
+                    .any(|topic| PRIVATE_TOPICS.contains(&topic.as_str()));
+                if private && !self.authenticated {
+                    (false, "error:request not authorized")
+                } else {
+                    for topic in topics {
+                        if op == "subscribe" {
+                            self.topics.insert(topic);
+                        } else {
+                            self.topics.remove(&topic);
+                        }
+                    }
+                    (true, "")
+                }
+            }
+            _ => (false, "error:unknown op"),
+        };
+        let reply = json!({
+            "success": success,
+            "ret_msg": ret_msg,
+            "conn_id": self.conn_id,
+            "request": {
+                "op": op,
+                "args": request["args"],
+            },
+        });
+        Some(reply.to_string())
+    }
+}
+
+/// Returns `true` if the connection query carries a valid signature.
+fn authenticate(state: &State, query: &str) -> bool {
+    let mut api_key = None;
+    let mut expires = None;
+    let mut signature = None;
+    for (key, value) in url::form_urlencoded::parse(query.as_bytes()) {
+        match key.as_ref() {
+            "api_key" => api_key = Some(value.into_owned()),
+            "expires" => expires = value.parse::<i64>().ok(),
+            "signature" => signature = Some(value.into_owned()),
+            _ => {}
+        }
+    }
+    match (api_key, expires, signature) {
+        (Some(api_key), Some(expires), Some(signature)) => {
+            api_key == state.api_key
+                && expires > state.now_ms()
+                && signature == crate::ws::sign(expires, &state.api_secret)
+        }
+        _ => false,
+    }
+}
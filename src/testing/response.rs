@@ -0,0 +1,128 @@
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+
+/// A request received by the mock server.
+#[derive(Clone, Debug)]
+pub struct MockRequest {
+    /// The HTTP method, `GET` or `POST`.
+    pub method: String,
+    /// The path of the request, without the query string.
+    pub path: String,
+    /// The query parameters (`GET`) or body fields (`POST`) of the request.
+    pub params: BTreeMap<String, String>,
+}
+
+impl MockRequest {
+    /// Returns the value of the given parameter.
+    /// * `name` - The name of the parameter.
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params.get(name).map(String::as_str)
+    }
+}
+
+/// A scripted response of the mock server.
+#[derive(Clone, Debug)]
+pub struct MockResponse {
+    pub(super) status: u16,
+    pub(super) body: MockBody,
+}
+
+#[derive(Clone, Debug)]
+pub(super) enum MockBody {
+    Envelope {
+        ret_code: i64,
+        ret_msg: String,
+        result: Value,
+        rate_limit: Option<(i64, i64, i64)>,
+    },
+    Raw(String),
+}
+
+impl MockResponse {
+    /// A successful response with the given result.
+    /// * `result` - The `result` of the response.
+    pub fn ok(result: Value) -> Self {
+        MockResponse {
+            status: 200,
+            body: MockBody::Envelope {
+                ret_code: 0,
+                ret_msg: "OK".to_owned(),
+                result,
+                rate_limit: None,
+            },
+        }
+    }
+
+    /// An error response with the given `ret_code`.
+    /// * `ret_code` - The `ret_code` of the response.
+    /// * `ret_msg` - The `ret_msg` of the response.
+    pub fn error(ret_code: i64, ret_msg: &str) -> Self {
+        MockResponse {
+            status: 200,
+            body: MockBody::Envelope {
+                ret_code,
+                ret_msg: ret_msg.to_owned(),
+                result: Value::Null,
+                rate_limit: None,
+            },
+        }
+    }
+
+    /// A response with the given HTTP status and an empty body.
+    /// * `status` - The HTTP status code.
+    pub fn status(status: u16) -> Self {
+        MockResponse {
+            status,
+            body: MockBody::Raw(String::new()),
+        }
+    }
+
+    /// A response with the given body, sent as is.
+    /// * `body` - The body of the response.
+    pub fn raw(body: &str) -> Self {
+        MockResponse {
+            status: 200,
+            body: MockBody::Raw(body.to_owned()),
+        }
+    }
+
+    /// Add the rate limit fields to the response.
+    /// * `remaining` - The `rate_limit_status` of the response.
+    /// * `limit` - The `rate_limit` of the response.
+    /// * `reset_ms` - The `rate_limit_reset_ms` of the response.
+    pub fn with_rate_limit(mut self, remaining: i64, limit: i64, reset_ms: i64) -> Self {
+        if let MockBody::Envelope { rate_limit, .. } = &mut self.body {
+            *rate_limit = Some((remaining, limit, reset_ms));
+        }
+        self
+    }
+
+    /// Returns the body of the response.
+    /// * `time_now` - The `time_now` of the response.
+    pub(super) fn body(&self, time_now: &str) -> String {
+        match &self.body {
+            MockBody::Envelope {
+                ret_code,
+                ret_msg,
+                result,
+                rate_limit,
+            } => {
+                let mut body = json!({
+                    "ret_code": ret_code,
+                    "ret_msg": ret_msg,
+                    "ext_code": "",
+                    "ext_info": "",
+                    "result": result,
+                    "time_now": time_now,
+                });
+                if let Some((remaining, limit, reset_ms)) = rate_limit {
+                    body["rate_limit_status"] = json!(remaining);
+                    body["rate_limit"] = json!(limit);
+                    body["rate_limit_reset_ms"] = json!(reset_ms);
+                }
+                body.to_string()
+            }
+            MockBody::Raw(body) => body.clone(),
+        }
+    }
+}
@@ -0,0 +1,639 @@
+use super::{MockRequest, MockResponse, State};
+use crate::{http::BybitErrorKind, Interval};
+use serde_json::{json, Map, Value};
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+/// The `recv_window` Bybit uses when a request doesn't specify one.
+const DEFAULT_RECV_WINDOW: i64 = 5000;
+
+/// Accept and serve HTTP connections until the task is aborted.
+pub(super) async fn serve(listener: TcpListener, state: Arc<Mutex<State>>) {
+    while let Ok((stream, _)) = listener.accept().await {
+        tokio::spawn(handle_connection(stream, state.clone()));
+    }
+}
+
+/// Serve a single request, the connection is closed afterwards.
+async fn handle_connection(mut stream: TcpStream, state: Arc<Mutex<State>>) {
+    let request = match read_request(&mut stream).await {
+        Some(request) => request,
+        None => return,
+    };
+    let (status, body) = {
+        let mut state = state.lock().unwrap();
+        let response = handle_request(&mut state, &request);
+        (response.status, response.body(&state.time_now()))
+    };
+    let head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        status,
+        reason(status),
+        body.len()
+    );
+    let _res = stream.write_all(head.as_bytes()).await;
+    let _res = stream.write_all(body.as_bytes()).await;
+    let _res = stream.shutdown().await;
+}
+
+/// A parsed HTTP request.
+struct RawRequest {
+    method: String,
+    path: String,
+    query: String,
+    body: Vec<u8>,
+}
+
+async fn read_request(stream: &mut TcpStream) -> Option<RawRequest> {
+    let mut buf = Vec::new();
+    let mut chunk = [0_u8; 4096];
+    let header_end = loop {
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos + 4;
+        }
+        let n = stream.read(&mut chunk).await.ok()?;
+        if n == 0 {
+            return None;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    };
+
+    let head = String::from_utf8_lossy(&buf[..header_end]).into_owned();
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next()?.split(' ');
+    let method = request_line.next()?.to_owned();
+    let target = request_line.next()?;
+    let (path, query) = match target.split_once('?') {
+        Some((path, query)) => (path.to_owned(), query.to_owned()),
+        None => (target.to_owned(), String::new()),
+    };
+    let content_length = lines
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+        .unwrap_or(0);
+
+    let mut body = buf[header_end..].to_vec();
+    while body.len() < content_length {
+        let n = stream.read(&mut chunk).await.ok()?;
+        if n == 0 {
+            return None;
+        }
+        body.extend_from_slice(&chunk[..n]);
+    }
+
+    Some(RawRequest {
+        method,
+        path,
+        query,
+        body,
+    })
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        403 => "Forbidden",
+        404 => "Not Found",
+        429 => "Too Many Requests",
+        500 => "Internal Server Error",
+        502 => "Bad Gateway",
+        503 => "Service Unavailable",
+        504 => "Gateway Timeout",
+        _ => "Unknown",
+    }
+}
+
+fn handle_request(state: &mut State, raw: &RawRequest) -> MockResponse {
+    // The urlencoded `key=value` pairs as signed by the client.
+    let pairs: Vec<(String, String)> = if raw.method == "POST" {
+        match serde_json::from_slice::<Map<String, Value>>(&raw.body) {
+            Ok(body) => body
+                .into_iter()
+                .filter_map(|(key, value)| match value {
+                    Value::Null => None,
+                    Value::String(value) => Some((key, value)),
+                    value => Some((key, value.to_string())),
+                })
+                .collect(),
+            Err(_) => {
+                return MockResponse::error(BybitErrorKind::ParamsError.code(), "invalid json")
+            }
+        }
+    } else {
+        url::form_urlencoded::parse(raw.query.as_bytes())
+            .into_owned()
+            .collect()
+    };
+
+    let request = MockRequest {
+        method: raw.method.clone(),
+        path: raw.path.clone(),
+        params: pairs.iter().cloned().collect(),
+    };
+    state.requests.push(request.clone());
+
+    if raw.path.contains("/private/") {
+        if let Err(response) = verify(state, &pairs) {
+            return response;
+        }
+    }
+
+    if let Some(response) = state
+        .scripted
+        .get_mut(&raw.path)
+        .and_then(|responses| responses.pop_front())
+    {
+        return response;
+    }
+    if let Some(response) = state.fixed.get(&raw.path) {
+        return response.clone();
+    }
+    emulate(state, &request)
+}
+
+/// Verify the api key, timestamp and signature of a private request.
+fn verify(state: &State, pairs: &[(String, String)]) -> Result<(), MockResponse> {
+    let param = |name: &str| {
+        pairs
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    };
+
+    if param("api_key") != Some(state.api_key.as_str()) {
+        return Err(error(BybitErrorKind::InvalidApiKey, "invalid api_key"));
+    }
+
+    let timestamp: i64 = match param("timestamp").and_then(|ts| ts.parse().ok()) {
+        Some(timestamp) => timestamp,
+        None => return Err(error(BybitErrorKind::ParamsError, "missing timestamp")),
+    };
+    let recv_window = param("recv_window")
+        .and_then(|window| window.parse().ok())
+        .unwrap_or(DEFAULT_RECV_WINDOW);
+    let now = state.now_ms();
+    if timestamp > now + 1000 || now - timestamp > recv_window {
+        return Err(error(
+            BybitErrorKind::RequestExpired,
+            "invalid request, please check your timestamp and recv_window param",
+        ));
+    }
+
+    let unsigned: Vec<(&str, &str)> = pairs
+        .iter()
+        .filter(|(key, _)| key != "sign")
+        .map(|(key, value)| (key.as_str(), value.as_str()))
+        .collect();
+    let query = serde_urlencoded::to_string(&unsigned).unwrap_or_default();
+    let mut params: Vec<&str> = query.split('&').collect();
+    params.sort_unstable();
+    let expected = crate::sign(&params.join("&"), &state.api_secret);
+    if param("sign") != Some(expected.as_str()) {
+        return Err(error(BybitErrorKind::InvalidSign, "error sign!"));
+    }
+
+    Ok(())
+}
+
+fn error(kind: BybitErrorKind, msg: &str) -> MockResponse {
+    MockResponse::error(kind.code(), msg)
+}
+
+/// Emulate the endpoint of the given request.
+fn emulate(state: &mut State, request: &MockRequest) -> MockResponse {
+    match request.path.as_str() {
+        "/v2/public/time" => MockResponse::ok(json!({})),
+        "/v2/public/symbols"
+        | "/v2/public/tickers"
+        | "/v2/public/orderBook/L2"
+        | "/v2/public/open-interest"
+        | "/v2/public/big-deal"
+        | "/v2/public/account-ratio"
+        | "/v2/public/trading-records"
+        | "/public/linear/recent-trading-records"
+        | "/v2/public/announcement"
+        | "/v2/public/liq-records"
+        | "/v2/public/risk-limit/list"
+        | "/public/linear/risk-limit"
+        | "/v2/private/exchange-order/list" => MockResponse::ok(json!([])),
+        "/v2/public/kline/list"
+        | "/public/linear/kline"
+        | "/v2/public/mark-price-kline"
+        | "/v2/public/index-price-kline"
+        | "/v2/public/premium-index-kline" => klines(state, request),
+        "/v2/private/wallet/balance" => MockResponse::ok(json!({})),
+        "/v2/private/account/api-key" => MockResponse::ok(json!([{
+            "api_key": state.api_key,
+            "type": "personal",
+            "user_id": 1,
+            "inviter_id": 0,
+            "ips": ["*"],
+            "note": "mock",
+            "permissions": ["Order", "Position"],
+            "created_at": "2021-01-01T00:00:00.000Z",
+            "expired_at": "",
+            "read_only": false,
+        }])),
+        "/v2/private/execution/list" => MockResponse::ok(json!({ "trade_list": null })),
+        "/private/linear/trade/execution/list" => {
+            MockResponse::ok(json!({ "current_page": 1, "data": null }))
+        }
+        "/v2/private/trade/closed-pnl/list" | "/private/linear/trade/closed-pnl/list" => {
+            MockResponse::ok(json!({ "current_page": 1, "data": null }))
+        }
+        "/v2/private/account/lcp" => MockResponse::ok(json!({ "lcp_list": [] })),
+        "/v2/public/funding/prev-funding-rate"
+        | "/public/linear/funding/prev-funding-rate"
+        | "/v2/private/funding/prev-funding"
+        | "/private/linear/funding/prev-funding" => funding(state, request),
+        "/v2/private/funding/predicted-funding" | "/private/linear/funding/predicted-funding" => {
+            MockResponse::ok(json!({
+                "predicted_funding_rate": 0.0001,
+                "predicted_funding_fee": 0,
+            }))
+        }
+        "/v2/private/position/list" => match request.param("symbol") {
+            Some(symbol) => MockResponse::ok(flat_position(state, symbol, "None")),
+            None => MockResponse::ok(json!([])),
+        },
+        "/private/linear/position/list" => match request.param("symbol") {
+            Some(symbol) => MockResponse::ok(json!([
+                flat_position(state, symbol, "Buy"),
+                flat_position(state, symbol, "Sell"),
+            ])),
+            None => MockResponse::ok(json!([])),
+        },
+        "/v2/private/position/leverage/save"
+        | "/private/linear/position/set-leverage"
+        | "/v2/private/position/change-position-margin"
+        | "/private/linear/position/add-margin"
+        | "/v2/private/position/trading-stop"
+        | "/private/linear/position/trading-stop"
+        | "/v2/private/position/risk-limit"
+        | "/private/linear/position/set-risk" => MockResponse::ok(Value::Null),
+        "/v2/private/wallet/fund/records" | "/v2/private/wallet/withdraw/list" => {
+            MockResponse::ok(json!({ "data": [] }))
+        }
+        "/v2/private/order/create"
+        | "/private/linear/order/create"
+        | "/v2/private/stop-order/create"
+        | "/private/linear/stop-order/create" => create_order(state, request),
+        "/v2/private/order"
+        | "/private/linear/order/search"
+        | "/v2/private/stop-order"
+        | "/private/linear/stop-order/search" => match find_order(state, request) {
+            Some(order) => MockResponse::ok(Value::Object(order.clone())),
+            None => error(BybitErrorKind::OrderNotExists, "order not exists"),
+        },
+        "/v2/private/order/list" | "/private/linear/order/list" => {
+            let orders: Vec<Value> = state
+                .orders
+                .iter()
+                .filter(|order| {
+                    !is_conditional(order)
+                        && Some(order["symbol"].as_str().unwrap_or("")) == request.param("symbol")
+                })
+                .map(|order| Value::Object(order.clone()))
+                .collect();
+            MockResponse::ok(json!({ "data": orders }))
+        }
+        "/v2/private/order/replace" | "/private/linear/order/replace" => {
+            let price = request.param("p_r_price").unwrap_or("0").to_owned();
+            match find_order(state, request) {
+                Some(order) => {
+                    order.insert("price".to_owned(), json!(price));
+                    MockResponse::ok(json!({ "order_id": order["order_id"] }))
+                }
+                None => error(BybitErrorKind::OrderNotExists, "order not exists"),
+            }
+        }
+        "/v2/private/order/cancel" | "/private/linear/order/cancel" => {
+            match find_order(state, request) {
+                Some(order) if is_open(order) => {
+                    order.insert("order_status".to_owned(), json!("Cancelled"));
+                    order.insert("cancel_type".to_owned(), json!("CancelByUser"));
+                    MockResponse::ok(json!({ "order_id": order["order_id"] }))
+                }
+                Some(_) => error(
+                    BybitErrorKind::OrderFilledOrCanceled,
+                    "order has been finished or canceled",
+                ),
+                None => error(BybitErrorKind::OrderNotExists, "order not exists"),
+            }
+        }
+        "/v2/private/order/cancelAll" | "/private/linear/order/cancel-all" => {
+            let symbol = request.param("symbol").unwrap_or("").to_owned();
+            let linear = request.path.contains("/linear/");
+            let cancelled: Vec<Value> = state
+                .orders
+                .iter_mut()
+                .filter(|order| {
+                    !is_conditional(order) && order["symbol"] == symbol.as_str() && is_open(order)
+                })
+                .map(|order| {
+                    order.insert("order_status".to_owned(), json!("Cancelled"));
+                    order.insert("cancel_type".to_owned(), json!("CancelByUser"));
+                    if linear {
+                        order["order_id"].clone()
+                    } else {
+                        json!({ "clOrdID": order["order_id"] })
+                    }
+                })
+                .collect();
+            MockResponse::ok(Value::Array(cancelled))
+        }
+        "/v2/private/stop-order/list" | "/private/linear/stop-order/list" => {
+            let symbol = request.param("symbol").unwrap_or("");
+            let status = request.param("stop_order_status");
+            let orders: Vec<Value> = state
+                .orders
+                .iter()
+                .rev()
+                .filter(|order| {
+                    is_conditional(order)
+                        && order["symbol"] == symbol
+                        && status.is_none_or(|status| order["order_status"] == status)
+                })
+                .map(|order| Value::Object(order.clone()))
+                .collect();
+            MockResponse::ok(json!({ "data": orders }))
+        }
+        "/v2/private/stop-order/replace" | "/private/linear/stop-order/replace" => {
+            let trigger_field = if request.path.contains("/linear/") {
+                "trigger_price"
+            } else {
+                "stop_px"
+            };
+            let fields = [
+                ("p_r_qty", "qty"),
+                ("p_r_price", "price"),
+                ("p_r_trigger_price", trigger_field),
+            ];
+            let updates: Vec<(&str, String)> = fields
+                .iter()
+                .filter_map(|(param, field)| Some((*field, request.param(param)?.to_owned())))
+                .collect();
+            match find_order(state, request) {
+                Some(order) if is_conditional(order) && is_open(order) => {
+                    for (field, value) in updates {
+                        order.insert(field.to_owned(), json!(value));
+                    }
+                    MockResponse::ok(json!({ "stop_order_id": order["stop_order_id"] }))
+                }
+                _ => error(
+                    BybitErrorKind::OrderNotExists,
+                    "order not exists or too late to replace",
+                ),
+            }
+        }
+        "/v2/private/stop-order/cancel" | "/private/linear/stop-order/cancel" => {
+            match find_order(state, request) {
+                Some(order) if is_conditional(order) && is_open(order) => {
+                    order.insert("order_status".to_owned(), json!("Deactivated"));
+                    MockResponse::ok(json!({ "stop_order_id": order["stop_order_id"] }))
+                }
+                _ => error(
+                    BybitErrorKind::OrderNotExists,
+                    "order not exists or too late to cancel",
+                ),
+            }
+        }
+        "/v2/private/stop-order/cancelAll" | "/private/linear/stop-order/cancel-all" => {
+            let symbol = request.param("symbol").unwrap_or("").to_owned();
+            let cancelled: Vec<Value> = state
+                .orders
+                .iter_mut()
+                .filter(|order| {
+                    is_conditional(order) && order["symbol"] == symbol.as_str() && is_open(order)
+                })
+                .map(|order| {
+                    order.insert("order_status".to_owned(), json!("Deactivated"));
+                    order["stop_order_id"].clone()
+                })
+                .collect();
+            if request.path.contains("/linear/") {
+                MockResponse::ok(Value::Array(cancelled))
+            } else {
+                let cancelled = cancelled
+                    .into_iter()
+                    .map(|id| json!({ "clOrdID": id }))
+                    .collect();
+                MockResponse::ok(Value::Array(cancelled))
+            }
+        }
+        _ => error(BybitErrorKind::PathNotFound, "request path not found"),
+    }
+}
+
+/// Returns the funding of the last 8 hour funding interval, at a constant rate of 0.01%.
+/// Positions are always flat, so the funding fee is zero.
+fn funding(state: &State, request: &MockRequest) -> MockResponse {
+    let symbol = match request.param("symbol") {
+        Some(symbol) => symbol,
+        None => return error(BybitErrorKind::ParamsError, "params error: symbol invalid"),
+    };
+    let now = state.now_ms() / 1000;
+    let funding_time = now - now.rem_euclid(8 * 3600);
+    if request.path.contains("/private/") {
+        MockResponse::ok(json!({
+            "symbol": symbol,
+            "side": "Buy",
+            "size": 0,
+            "funding_rate": 0.0001,
+            "exec_fee": 0,
+            "exec_timestamp": funding_time,
+        }))
+    } else {
+        MockResponse::ok(json!({
+            "symbol": symbol,
+            "funding_rate": "0.0001",
+            "funding_rate_timestamp": funding_time,
+        }))
+    }
+}
+
+/// Generate flat klines starting at `from`, aligned to the interval, up to the current time.
+/// The klines carry the fields of traded price and index klines alike.
+fn klines(state: &State, request: &MockRequest) -> MockResponse {
+    let interval: Interval = match request.param("interval").map(str::parse) {
+        Some(Ok(interval)) => interval,
+        _ => {
+            return error(
+                BybitErrorKind::ParamsError,
+                "params error: interval invalid",
+            )
+        }
+    };
+    let from: i64 = match request.param("from").map(str::parse) {
+        Some(Ok(from)) => from,
+        _ => return error(BybitErrorKind::ParamsError, "params error: from invalid"),
+    };
+    let limit: i64 = request
+        .param("limit")
+        .and_then(|limit| limit.parse().ok())
+        .unwrap_or(200)
+        .clamp(1, 200);
+    let symbol = request.param("symbol").unwrap_or("");
+    let seconds = interval.seconds();
+    let now = state.now_ms() / 1000;
+    let klines: Vec<Value> = (0..limit)
+        .map(|i| from - from.rem_euclid(seconds) + i * seconds)
+        .take_while(|open_time| *open_time <= now)
+        .map(|open_time| {
+            json!({
+                "symbol": symbol,
+                "interval": interval,
+                "period": interval,
+                "open_time": open_time,
+                "open": "100",
+                "high": "100",
+                "low": "100",
+                "close": "100",
+                "volume": "10",
+                "turnover": "0.1",
+            })
+        })
+        .collect();
+    MockResponse::ok(Value::Array(klines))
+}
+
+/// Returns an empty position of the given symbol.
+fn flat_position(state: &State, symbol: &str, side: &str) -> Value {
+    let position_idx = match side {
+        "Buy" => 1,
+        "Sell" => 2,
+        _ => 0,
+    };
+    json!({
+        "user_id": 1,
+        "symbol": symbol,
+        "side": side,
+        "size": 0,
+        "position_value": "0",
+        "entry_price": "0",
+        "liq_price": "0",
+        "bust_price": "0",
+        "leverage": "10",
+        "is_isolated": false,
+        "auto_add_margin": 0,
+        "position_margin": "0",
+        "take_profit": "0",
+        "stop_loss": "0",
+        "trailing_stop": "0",
+        "realised_pnl": "0",
+        "risk_id": 1,
+        "occ_closing_fee": "0",
+        "cum_realised_pnl": "0",
+        "position_status": "Normal",
+        "position_idx": position_idx,
+        "created_at": state.time_now(),
+    })
+}
+
+fn is_open(order: &Map<String, Value>) -> bool {
+    matches!(
+        order["order_status"].as_str(),
+        Some("Created") | Some("New") | Some("PartiallyFilled") | Some("Untriggered")
+    )
+}
+
+fn is_conditional(order: &Map<String, Value>) -> bool {
+    order["stop_order_type"] == "Stop"
+}
+
+fn find_order<'a>(
+    state: &'a mut State,
+    request: &MockRequest,
+) -> Option<&'a mut Map<String, Value>> {
+    let order_id = request
+        .param("order_id")
+        .or_else(|| request.param("stop_order_id"));
+    let order_link_id = request.param("order_link_id");
+    state
+        .orders
+        .iter_mut()
+        .find(|order| match (order_id, order_link_id) {
+            (Some(id), _) => order["order_id"] == id,
+            (None, Some(link_id)) => order["order_link_id"] == link_id,
+            (None, None) => false,
+        })
+}
+
+/// Create an order from the parameters of the request.
+/// The order carries the fields of active, linear and conditional orders alike.
+fn create_order(state: &mut State, request: &MockRequest) -> MockResponse {
+    let param = |name: &str| request.param(name).unwrap_or("");
+    let number = |name: &str| request.param(name).unwrap_or("0");
+    let flag = |name: &str| request.param(name) == Some("true");
+
+    if let Some(link_id) = request.param("order_link_id") {
+        if state
+            .orders
+            .iter()
+            .any(|order| order["order_link_id"] == link_id)
+        {
+            return error(BybitErrorKind::ParamsError, "duplicate order_link_id");
+        }
+    }
+
+    let id = format!("{:08x}-0000-4000-8000-000000000000", state.next_order_id);
+    state.next_order_id += 1;
+    let conditional = request.path.contains("/stop-order/");
+    let status = if conditional { "Untriggered" } else { "New" };
+    let now = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+
+    let mut order = json!({
+        "order_id": id,
+        "stop_order_id": id,
+        "user_id": 1,
+        "order_link_id": param("order_link_id"),
+        "symbol": param("symbol"),
+        "side": param("side"),
+        "order_type": param("order_type"),
+        "price": number("price"),
+        "qty": number("qty"),
+        "time_in_force": param("time_in_force"),
+        "order_status": status,
+        "last_exec_time": "0",
+        "last_exec_price": "0",
+        "leaves_qty": number("qty"),
+        "leaves_value": "0",
+        "cum_exec_qty": "0",
+        "cum_exec_value": "0",
+        "cum_exec_fee": "0",
+        "reject_reason": "",
+        "create_type": "CreateByUser",
+        "cancel_type": "UNKNOWN",
+        "created_at": now,
+        "updated_at": now,
+        "created_time": now,
+        "updated_time": now,
+        "take_profit": number("take_profit"),
+        "stop_loss": number("stop_loss"),
+        "tp_trigger_by": request.param("tp_trigger_by").unwrap_or("UNKNOWN"),
+        "sl_trigger_by": request.param("sl_trigger_by").unwrap_or("UNKNOWN"),
+        "reduce_only": flag("reduce_only"),
+        "close_on_trigger": flag("close_on_trigger"),
+        "position_idx": number("position_idx").parse::<i64>().unwrap_or(0),
+        "trigger_by": request.param("trigger_by").unwrap_or("LastPrice"),
+        "base_price": number("base_price"),
+        "stop_px": number("stop_px"),
+        "remark": "",
+        "stop_order_type": if conditional { "Stop" } else { "" },
+    });
+    // Linear conditional orders report the trigger price as `trigger_price`.
+    if let Value::Object(order) = &mut order {
+        if conditional && request.path.contains("/linear/") {
+            if let Some(stop_px) = order.remove("stop_px") {
+                order.insert("trigger_price".to_owned(), stop_px);
+            }
+        }
+    }
+    if let Value::Object(order) = &order {
+        state.orders.push(order.clone());
+    }
+    MockResponse::ok(order)
+}
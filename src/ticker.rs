@@ -1,3 +1,4 @@
+use crate::{deserialize::optional_decimal, Price, Qty};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Copy, Clone, Debug)]
@@ -19,31 +20,31 @@ pub struct Ticker {
     /// Symbol
     pub symbol: String,
     /// Purchase price of the first order
-    pub bid_price: String,
+    pub bid_price: Price,
     /// Selling price of the first order
-    pub ask_price: String,
+    pub ask_price: Price,
     /// Latest transaction price
-    pub last_price: String,
+    pub last_price: Price,
     /// Index price
-    pub index_price: String,
+    pub index_price: Price,
     /// Mark price
-    pub mark_price: String,
+    pub mark_price: Price,
     /// Direction of price change
     pub last_tick_direction: TickDirection,
     /// Price of 24 hours ago
-    pub prev_price_24h: String,
+    pub prev_price_24h: Price,
     /// Percentage change of market price relative to 24h
     pub price_24h_pcnt: String,
     /// Highest price in the last 24 hours
-    pub high_price_24h: String,
+    pub high_price_24h: Price,
     /// Lowest price in the last 24 hours
-    pub low_price_24h: String,
+    pub low_price_24h: Price,
     /// Hourly market price an hour ago
-    pub prev_price_1h: String,
+    pub prev_price_1h: Price,
     /// Percentage change of market price relative to 1 hour ago
     pub price_1h_pcnt: String,
     /// Open interest
-    pub open_interest: f64,
+    pub open_interest: Qty,
     /// Open position value
     pub open_value: String,
     /// Total turnover
@@ -51,9 +52,9 @@ pub struct Ticker {
     /// Turnover for 24h
     pub turnover_24h: String,
     /// Total volume
-    pub total_volume: f64,
+    pub total_volume: Qty,
     /// Trading volume in the last 24 hours
-    pub volume_24h: f64,
+    pub volume_24h: Qty,
     /// Funding rate
     pub funding_rate: String,
     /// Predicted funding rate
@@ -65,7 +66,8 @@ pub struct Ticker {
     /// Delivery fee rate of Futures contract
     pub delivery_fee_rate: String,
     /// Predicted delivery price of Futures contract
-    pub predicted_delivery_price: String,
+    #[serde(deserialize_with = "optional_decimal", default)]
+    pub predicted_delivery_price: Option<Price>,
     /// Delivery time of Futures contract
     pub delivery_time: String,
 }
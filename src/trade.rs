@@ -1,4 +1,94 @@
+use crate::{
+    deserialize::{rfc3339_millis, string_or_integer},
+    order::Side,
+    Price, Qty,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
 pub struct TradeId(String);
+
+impl std::fmt::Display for TradeId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// A public trade, as returned by `rest::FetchRecentTrades` or converted from a `ws::Trade`.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(from = "RecentTradeRecord")]
+pub struct PublicTrade {
+    /// Trade id
+    pub trade_id: TradeId,
+    /// Symbol
+    pub symbol: String,
+    /// Side of the taker
+    pub side: Side,
+    /// Trade price
+    pub price: Price,
+    /// Trade size
+    pub size: Qty,
+    /// Trade time (in milliseconds)
+    pub trade_time_ms: i64,
+}
+
+/// A trade as returned by the trading records endpoints.
+/// Inverse records carry a numeric id and only an RFC 3339 `time`.
+#[derive(Deserialize)]
+struct RecentTradeRecord {
+    #[serde(deserialize_with = "string_or_integer")]
+    id: String,
+    symbol: String,
+    side: Side,
+    price: Price,
+    qty: Qty,
+    #[serde(rename = "time", deserialize_with = "rfc3339_millis")]
+    time_ms: i64,
+    trade_time_ms: Option<i64>,
+}
+
+impl From<RecentTradeRecord> for PublicTrade {
+    fn from(record: RecentTradeRecord) -> Self {
+        let trade_time_ms = record.trade_time_ms.unwrap_or(record.time_ms);
+        PublicTrade {
+            trade_id: TradeId(record.id),
+            symbol: record.symbol,
+            side: record.side,
+            price: record.price,
+            size: record.qty,
+            trade_time_ms,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let trade: PublicTrade = serde_json::from_str(
+            r#"{"id":7724919,"symbol":"BTCUSD","price":9499.5,"qty":9500,"side":"Buy",
+                "time":"2019-11-19T08:03:04.000Z"}"#,
+        )
+        .unwrap();
+        assert_eq!(trade.trade_id.to_string(), "7724919");
+        assert_eq!(trade.size, Qty::from(9500));
+        assert_eq!(trade.trade_time_ms, 1574150584000);
+
+        let trade: PublicTrade = serde_json::from_str(
+            r#"{"id":"18b0a3b2-8d4a-5d0e-9a65-0a0d2b5f7b9c","symbol":"BTCUSDT","price":9499.5,
+                "qty":0.1,"side":"Sell","time":"2019-11-19T08:03:04.000Z","trade_time_ms":1574150584123}"#,
+        )
+        .unwrap();
+        assert_eq!(trade.side, Side::Sell);
+        assert_eq!(trade.size, "0.1".parse().unwrap());
+        assert_eq!(trade.trade_time_ms, 1574150584123);
+
+        let result: Result<PublicTrade, _> = serde_json::from_str(
+            r#"{"id":7724919,"symbol":"BTCUSD","price":9499.5,"qty":9500,"side":"Buy",
+                "time":"not a time"}"#,
+        );
+        assert!(result.is_err());
+    }
+}
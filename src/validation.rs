@@ -0,0 +1,41 @@
+use crate::{Price, Qty};
+use thiserror::Error as ThisError;
+
+/// The reason an order was rejected by `Symbol::validate_order`.
+#[derive(Debug, Clone, PartialEq, ThisError)]
+pub enum ValidationError {
+    #[error("Order is for symbol {actual}, expected {expected}")]
+    SymbolMismatch { expected: String, actual: String },
+    #[error("Symbol {0} is not trading")]
+    NotTrading(String),
+    #[error("Limit orders require a price")]
+    MissingPrice,
+    #[error("{field} {value} is below the minimum price {min}")]
+    PriceTooLow {
+        field: &'static str,
+        value: Price,
+        min: Price,
+    },
+    #[error("{field} {value} is above the maximum price {max}")]
+    PriceTooHigh {
+        field: &'static str,
+        value: Price,
+        max: Price,
+    },
+    #[error("{field} {value} is not a multiple of the tick size {tick_size}")]
+    InvalidTickSize {
+        field: &'static str,
+        value: Price,
+        tick_size: Price,
+    },
+    #[error("Quantity {value} is below the minimum quantity {min}")]
+    QtyTooLow { value: Qty, min: Qty },
+    #[error("Quantity {value} is above the maximum quantity {max}")]
+    QtyTooHigh { value: Qty, max: Qty },
+    #[error("Quantity {value} is not a multiple of the quantity step {qty_step}")]
+    InvalidQtyStep { value: Qty, qty_step: Qty },
+    #[error("Leverage {value} is outside of the range {min} to {max}")]
+    LeverageOutOfRange { value: f64, min: f64, max: f64 },
+    #[error("Leverage {value} is not a multiple of the leverage step {leverage_step}")]
+    InvalidLeverageStep { value: f64, leverage_step: String },
+}
@@ -1,4 +1,4 @@
-use crate::{deserialize::string_or_number, UserId};
+use crate::{deserialize::string_or_number, Price, Qty, UserId};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
@@ -32,31 +32,31 @@ pub struct WalletId(i64);
 #[derive(Deserialize, Debug, Copy, Clone, Default)]
 pub struct Wallet {
     /// User equity
-    pub equity: f64,
+    pub equity: Qty,
     /// Available balance (wallet balance - used margin)
-    pub available_balance: f64,
+    pub available_balance: Qty,
     /// Used margin
-    pub used_margin: f64,
+    pub used_margin: Qty,
     /// Pre-occupied order margin
-    pub order_margin: f64,
+    pub order_margin: Qty,
     /// Position margin
-    pub position_margin: f64,
+    pub position_margin: Qty,
     /// Position closing fee occupied
-    pub occ_closing_fee: f64,
+    pub occ_closing_fee: Qty,
     /// Pre-occupied funding fee
-    pub occ_funding_fee: f64,
+    pub occ_funding_fee: Qty,
     /// Wallet data endpoints
-    pub wallet_balance: f64,
+    pub wallet_balance: Qty,
     /// Today's realised pnl
-    pub realised_pnl: f64,
+    pub realised_pnl: Qty,
     /// Today's unrealised pnl
-    pub unrealised_pnl: f64,
+    pub unrealised_pnl: Qty,
     /// Accumulated realised pnl (all-time)
-    pub cum_realised_pnl: f64,
+    pub cum_realised_pnl: Qty,
     /// Experience gold
-    pub given_cash: f64,
+    pub given_cash: Qty,
     /// Service cash is used for user's service charge
-    pub service_cash: f64,
+    pub service_cash: Qty,
 }
 
 #[derive(Deserialize, Debug, Copy, Clone)]
@@ -70,12 +70,10 @@ pub struct WalletFundRecord {
     pub wallet_id: WalletId,
     #[serde(rename = "type")]
     pub fund_type: WalletFundType,
-    #[serde(deserialize_with = "string_or_number")]
-    pub amount: f64,
+    pub amount: Qty,
     pub tx_id: String,
     pub address: String,
-    #[serde(deserialize_with = "string_or_number")]
-    pub wallet_balance: f64,
+    pub wallet_balance: Qty,
     pub exec_time: String,
     pub cross_seq: i64,
 }
@@ -89,8 +87,7 @@ pub struct WalletWithdrawRecord {
     pub user_id: UserId,
     pub coin: String,
     pub status: WithdrawStatus,
-    #[serde(deserialize_with = "string_or_number")]
-    pub amount: f64,
+    pub amount: Qty,
     #[serde(deserialize_with = "string_or_number")]
     pub fee: f64,
     pub address: String,
@@ -106,12 +103,12 @@ pub struct AssetExchangeRecordId(i64);
 #[derive(Deserialize, Debug, Clone)]
 pub struct AssetExchangeRecord {
     pub id: AssetExchangeRecordId,
-    pub exchange_rate: f64,
+    pub exchange_rate: Price,
     pub from_coin: String,
     pub to_coin: String,
-    pub to_amount: f64,
+    pub to_amount: Qty,
     pub from_fee: f64,
-    pub from_amount: f64,
+    pub from_amount: Qty,
     pub created_at: String,
 }
 
@@ -172,15 +169,18 @@ mod tests {
         wallets.insert(
             "BTC".to_owned(),
             Wallet {
-                equity: 1.3,
-                available_balance: 35624.5,
+                equity: "1.3".parse().unwrap(),
+                available_balance: "35624.5".parse().unwrap(),
                 ..Default::default()
             },
         );
         let wallets = Wallets(wallets);
         assert!(wallets.get("BTC").is_some());
         assert!(wallets.get("ETH").is_none());
-        assert_eq!(wallets.get("BTC").unwrap().equity, 1.3);
-        assert_eq!(wallets.get("BTC").unwrap().available_balance, 35624.5);
+        assert_eq!(wallets.get("BTC").unwrap().equity.to_string(), "1.3");
+        assert_eq!(
+            wallets.get("BTC").unwrap().available_balance.to_string(),
+            "35624.5"
+        );
     }
 }
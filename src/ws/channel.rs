@@ -1,3 +1,5 @@
+use crate::Interval;
+
 #[derive(Eq, PartialEq, Clone, Debug)]
 pub enum Channel {
     // Public
@@ -6,7 +8,7 @@ pub enum Channel {
     Trade,
     Insurance,
     InstrumentInfo(String),
-    KlineV2(String, String),
+    KlineV2(String, Interval),
     Liquidation,
 
     // Private
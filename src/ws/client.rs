@@ -1,5 +1,6 @@
-use super::{sign, Channel, Data, Error, IntoMessage, Message, Response, Result};
-use chrono::{Duration, Utc};
+use super::{sign, Channel, Data, Error, IntoMessage, Message, ReconnectPolicy, Response, Result};
+use crate::{Clock, ContractType, SymbolRegistry};
+use chrono::Duration;
 use futures_util::{
     ready,
     task::{Context, Poll},
@@ -23,6 +24,11 @@ pub struct Client {
     channels: Vec<Channel>,
     ping_timer: Interval,
     buf: VecDeque<Data>,
+    clock: Clock,
+    symbol_registry: Option<SymbolRegistry>,
+    reconnect_policy: Option<ReconnectPolicy>,
+    reconnect_attempt: u32,
+    reconnecting: Option<Pin<Box<dyn Future<Output = Result<Client>> + Send>>>,
 }
 
 impl Client {
@@ -39,14 +45,50 @@ impl Client {
             channels: Vec::new(),
             ping_timer: time::interval(time::Duration::from_secs(15)),
             buf: VecDeque::new(),
+            clock: Clock::new(),
+            symbol_registry: None,
+            reconnect_policy: None,
+            reconnect_attempt: 0,
+            reconnecting: None,
         }
     }
 
+    /// Set the clock used to compute the expiry of the authentication signature.
+    /// - `clock` - The clock, see `Clock::sync` to synchronize it with the server.
+    pub fn set_clock(&mut self, clock: Clock) {
+        self.clock = clock;
+    }
+
+    /// Set the registry used to determine the contract type of subscribed symbols.
+    /// Without a registry, or for unknown symbols, the contract type is derived from the name.
+    /// - `registry` - The registry, `None` to derive contract types from names only.
+    pub fn set_symbol_registry(&mut self, registry: Option<SymbolRegistry>) {
+        self.symbol_registry = registry;
+    }
+
+    /// Enable automatic reconnects when the connection drops while streaming.
+    /// After a successful reconnect all subscribed channels are resubscribed
+    /// and a `Data::Reconnected` item is emitted.
+    /// - `policy` - The reconnect policy to use.
+    pub fn set_reconnect_policy(&mut self, policy: ReconnectPolicy) {
+        self.reconnect_policy = Some(policy);
+    }
+
+    /// Disable automatic reconnects.
+    pub fn clear_reconnect_policy(&mut self) {
+        self.reconnect_policy = None;
+    }
+
     /// Returns `true` is this client is connected.
     pub fn is_connected(&self) -> bool {
         self.stream.is_some()
     }
 
+    /// Returns the channels this client is subscribed to.
+    pub fn channels(&self) -> &[Channel] {
+        &self.channels
+    }
+
     /// Returns `true` is this client is authenticated.
     pub fn is_authenticated(&self) -> bool {
         !self.api_key.is_empty()
@@ -54,7 +96,7 @@ impl Client {
 
     /// Connect the client.
     pub async fn connect(&mut self) -> Result<()> {
-        let expires = (Utc::now() + Duration::seconds(2)).timestamp_millis();
+        let expires = (self.clock.now() + Duration::seconds(2)).timestamp_millis();
         let signature = sign(expires, &self.api_secret);
         let url = format!(
             "{}?api_key={}&expires={}&signature={}",
@@ -67,6 +109,18 @@ impl Client {
         Ok(())
     }
 
+    /// Reconnect the client and resubscribe to all subscribed channels.
+    pub async fn reconnect(&mut self) -> Result<()> {
+        self.stream = None;
+        self.connect().await?;
+        let channels = self.channels.clone();
+        if !channels.is_empty() {
+            self.subscribe_or_unsubscribe(&channels, true).await?;
+        }
+
+        Ok(())
+    }
+
     /// Disconnect the client.
     pub async fn disconnect(&mut self) -> Result<()> {
         let message = Message::Close(None);
@@ -153,7 +207,7 @@ impl Client {
                 Channel::Insurance => "insurance".to_owned(),
                 Channel::InstrumentInfo(symbol) => format!("instrument_info.100ms.{}", symbol),
                 Channel::KlineV2(symbol, interval) => {
-                    if Self::is_inverse(symbol) {
+                    if self.contract_type(symbol).is_inverse() {
                         format!("klineV2.{}.{}", interval, symbol)
                     } else {
                         format!("candle.{}.{}", interval, symbol)
@@ -189,33 +243,65 @@ impl Client {
 
     async fn next_response(&mut self) -> Result<Response> {
         loop {
-            if let Some(stream) = self.stream.as_mut() {
-                tokio::select! {
-                    _ = self.ping_timer.tick() => {
-                        self.ping().await?;
-                    },
-                    Some(msg) = stream.next() => {
-                        let msg = msg?;
-                        if let Message::Text(text) = msg {
+            let stream = match self.stream.as_mut() {
+                Some(stream) => stream,
+                None => return Err(Error::NotConnected),
+            };
+            tokio::select! {
+                _ = self.ping_timer.tick() => {
+                    if let Err(e) = self.ping().await {
+                        self.stream = None;
+                        return Err(e);
+                    }
+                },
+                msg = stream.next() => {
+                    match msg {
+                        Some(Ok(Message::Text(text))) => {
                             let response: Response = serde_json::from_str(&text)?;
                             return Ok(response)
-                        } else if let Message::Close(_) = msg {
+                        }
+                        Some(Ok(Message::Close(_))) | None => {
                             self.stream = None;
                             return Err(Error::NotConnected)
                         }
+                        Some(Ok(_)) => {}
+                        Some(Err(e)) => {
+                            self.stream = None;
+                            return Err(e.into())
+                        }
                     }
                 }
             }
         }
     }
 
+    /// Schedule a reconnect attempt, returns `false` if the reconnect policy doesn't allow it.
+    fn schedule_reconnect(&mut self) -> bool {
+        let policy = match self.reconnect_policy.as_ref() {
+            Some(policy) if policy.allows(self.reconnect_attempt) => policy,
+            _ => return false,
+        };
+        let delay = policy.backoff(self.reconnect_attempt);
+        self.reconnect_attempt += 1;
+
+        let mut client = Client::new(&self.base_url, &self.api_key, &self.api_secret);
+        client.channels = self.channels.clone();
+        client.clock = self.clock.clone();
+        client.symbol_registry = self.symbol_registry.clone();
+        self.reconnecting = Some(Box::pin(async move {
+            time::sleep(delay).await;
+            client.reconnect().await?;
+            Ok(client)
+        }));
+
+        true
+    }
+
     fn handle_response(&mut self, response: Response) {
         match response {
             Response::Request(_res) => {}
-            Response::OrderbookSnapshot(res) => self
-                .buf
-                .extend(res.data.into_iter().map(Data::OrderbookSnapshot)),
-            Response::OrderbookDelta(res) => self.buf.push_back(Data::OrderbookDelta(res.data)),
+            Response::OrderbookSnapshot(res) => self.buf.push_back(Data::OrderbookSnapshot(res)),
+            Response::OrderbookDelta(res) => self.buf.push_back(Data::OrderbookDelta(res)),
             Response::Trade(res) => self.buf.extend(res.data.into_iter().map(Data::Trade)),
             Response::Insurance(res) => self.buf.extend(res.data.into_iter().map(Data::Insurance)),
             Response::InstrumentInfoSnapshot(res) => {
@@ -227,7 +313,9 @@ impl Client {
             Response::KlineV2(res) => self.buf.extend(res.data.into_iter().map(Data::KlineV2)),
             Response::Liquidation(res) => self.buf.push_back(Data::Liquidation(res.data)),
             Response::Position(res) => self.buf.extend(res.data.into_iter().map(Data::Position)),
-            Response::Execution(res) => self.buf.extend(res.data.into_iter().map(Data::Execution)),
+            Response::Execution(res) => self
+                .buf
+                .extend(res.data.into_iter().map(|e| Data::Execution(e.into()))),
             Response::Order(res) => self.buf.extend(res.data.into_iter().map(Data::Order)),
             Response::StopOrder(res) => self.buf.extend(res.data.into_iter().map(Data::StopOrder)),
         }
@@ -251,8 +339,12 @@ impl Client {
         Ok(None)
     }
 
-    fn is_inverse(symbol: &str) -> bool {
-        !symbol.ends_with('T')
+    fn contract_type(&self, symbol: &str) -> ContractType {
+        self.symbol_registry
+            .as_ref()
+            .and_then(|registry| registry.get(symbol))
+            .map(|symbol| symbol.contract_type())
+            .unwrap_or_else(|| ContractType::from_name(symbol))
     }
 }
 
@@ -263,15 +355,41 @@ impl Stream for Client {
             if let Some(data) = self.buf.pop_front() {
                 return Poll::Ready(Some(Ok(data)));
             }
+            if let Some(reconnecting) = self.reconnecting.as_mut() {
+                let res = ready!(reconnecting.as_mut().poll(cx));
+                self.reconnecting = None;
+                match res {
+                    Ok(client) => {
+                        self.stream = client.stream;
+                        self.reconnect_attempt = 0;
+                        self.buf.push_back(Data::Reconnected);
+                        self.buf.extend(client.buf);
+                    }
+                    Err(e) => {
+                        if !self.schedule_reconnect() {
+                            return Poll::Ready(Some(Err(e)));
+                        }
+                    }
+                }
+                continue;
+            }
+            let was_connected = self.is_connected();
             let response = {
                 let mut next_response = self.next_response();
                 let pinned: Pin<_> = unsafe { Pin::new_unchecked(&mut next_response) };
-                match ready!(pinned.poll(cx)) {
-                    Ok(response) => response,
-                    Err(e) => return Poll::Ready(Some(Err(e))),
-                }
+                ready!(pinned.poll(cx))
             };
-            self.handle_response(response);
+            match response {
+                Ok(response) => self.handle_response(response),
+                Err(e) => {
+                    // Only reconnect if the connection dropped while streaming,
+                    // not after an explicit disconnect.
+                    if was_connected && !self.is_connected() && self.schedule_reconnect() {
+                        continue;
+                    }
+                    return Poll::Ready(Some(Err(e)));
+                }
+            }
         }
     }
 }
@@ -279,33 +397,125 @@ impl Stream for Client {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::MockServer;
+    use crate::Interval;
+
+    fn trade() -> serde_json::Value {
+        serde_json::json!({
+            "topic": "trade.BTCUSD",
+            "data": [{
+                "timestamp": "2020-01-12T16:59:59.000Z",
+                "trade_time_ms": 1582793344685_i64,
+                "symbol": "BTCUSD",
+                "side": "Sell",
+                "size": 328,
+                "price": 8098,
+                "tick_direction": "MinusTick",
+                "trade_id": "00c706e1-ba52-5bb0-98d0-bf694bdc69f7",
+                "cross_seq": 1052816407
+            }]
+        })
+    }
 
     #[tokio::test]
     async fn connect() {
-        let mut client = Client::new(TESTNET, "", "");
+        let server = MockServer::start().await.unwrap();
+        let mut client = Client::new(&server.ws_url(), "", "");
         assert!(client.connect().await.is_ok());
     }
 
     #[tokio::test]
     async fn ping() {
-        let mut client = Client::new(TESTNET, "", "");
+        let server = MockServer::start().await.unwrap();
+        let mut client = Client::new(&server.ws_url(), "", "");
         assert!(client.connect().await.is_ok());
         assert!(client.ping().await.is_ok());
     }
 
     #[tokio::test]
     async fn subscribe() {
-        let mut client = Client::new(TESTNET, "", "");
+        let server = MockServer::start().await.unwrap();
+        let mut client = Client::new(&server.ws_url(), "", "");
         assert!(client.connect().await.is_ok());
         assert!(client.subscribe(&[Channel::Trade]).await.is_ok());
         assert!(client.subscribe(&[Channel::Order]).await.is_err());
     }
 
+    #[tokio::test]
+    async fn subscribe_authenticated() {
+        let server = MockServer::start().await.unwrap();
+        let mut client = server.ws_client();
+        assert!(client.connect().await.is_ok());
+        assert!(client.subscribe(&[Channel::Order]).await.is_ok());
+
+        let mut client = Client::new(&server.ws_url(), "mock-api-key", "wrong-secret");
+        assert!(client.connect().await.is_ok());
+        assert!(matches!(
+            client.subscribe(&[Channel::Order]).await,
+            Err(Error::SubscriptionFailed(Channel::Order))
+        ));
+    }
+
     #[tokio::test]
     async fn stream() {
-        let mut client = Client::new(TESTNET, "", "");
+        let server = MockServer::start().await.unwrap();
+        let mut client = Client::new(&server.ws_url(), "", "");
         assert!(client.connect().await.is_ok());
         assert!(client.subscribe(&[Channel::Trade]).await.is_ok());
-        assert!(client.next().await.is_some());
+        server.publish("trade", trade());
+        assert!(matches!(client.next().await, Some(Ok(Data::Trade(_)))));
+    }
+
+    #[tokio::test]
+    async fn kline_topics() {
+        let kline = |topic: &str| {
+            serde_json::json!({
+                "topic": topic,
+                "data": [{
+                    "start": 1572425640,
+                    "end": 1572425700,
+                    "open": 9200,
+                    "close": 9202.5,
+                    "high": 9202.5,
+                    "low": 9196,
+                    "volume": 81790,
+                    "turnover": 8.889247899999999,
+                    "confirm": false,
+                    "cross_seq": 297503466,
+                    "timestamp": "1572425676958323"
+                }],
+                "timestamp_e6": 1572425677047994_i64
+            })
+        };
+        let server = MockServer::start().await.unwrap();
+        let mut client = Client::new(&server.ws_url(), "", "");
+        assert!(client.connect().await.is_ok());
+        let channels = [
+            Channel::KlineV2("BTCUSDZ22".to_owned(), Interval::Min1),
+            Channel::KlineV2("BTCUSDT".to_owned(), Interval::Min1),
+        ];
+        assert!(client.subscribe(&channels).await.is_ok());
+        server.publish("klineV2.1.BTCUSDZ22", kline("klineV2.1.BTCUSDZ22"));
+        assert!(matches!(client.next().await, Some(Ok(Data::KlineV2(_)))));
+        server.publish("candle.1.BTCUSDT", kline("candle.1.BTCUSDT"));
+        assert!(matches!(client.next().await, Some(Ok(Data::KlineV2(_)))));
+    }
+
+    #[tokio::test]
+    async fn reconnect() {
+        let server = MockServer::start().await.unwrap();
+        let mut client = Client::new(&server.ws_url(), "", "");
+        client.set_reconnect_policy(ReconnectPolicy {
+            initial_backoff: std::time::Duration::from_millis(10),
+            jitter: 0.0,
+            ..Default::default()
+        });
+        assert!(client.connect().await.is_ok());
+        assert!(client.subscribe(&[Channel::Trade]).await.is_ok());
+
+        server.disconnect_all();
+        assert!(matches!(client.next().await, Some(Ok(Data::Reconnected))));
+        server.publish("trade", trade());
+        assert!(matches!(client.next().await, Some(Ok(Data::Trade(_)))));
     }
 }
@@ -7,7 +7,7 @@ pub type Result<T> = std::result::Result<T, Error>;
 #[derive(Debug, ThisError)]
 pub enum Error {
     #[error("Ws Error: {0:?}")]
-    Ws(WsError),
+    Ws(Box<WsError>),
     #[error("Json Error: {0:?}")]
     Json(serde_json::Error),
     #[error("Not Connected")]
@@ -20,11 +20,13 @@ pub enum Error {
     SubscriptionFailed(Channel),
     #[error("Not Subscribed: {0:?}")]
     NotSubscribed(Channel),
+    #[error("Order Book Out Of Sync: {0}")]
+    OrderBookOutOfSync(String),
 }
 
 impl From<WsError> for Error {
     fn from(err: WsError) -> Self {
-        Self::Ws(err)
+        Self::Ws(Box::new(err))
     }
 }
 
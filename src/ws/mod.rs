@@ -2,11 +2,15 @@ mod channel;
 mod client;
 mod error;
 mod message;
+mod order_book;
+mod reconnect;
 mod response;
 mod sign;
 pub use channel::*;
 pub use client::*;
 pub use error::*;
 pub use message::*;
+pub use order_book::*;
+pub use reconnect::*;
 pub use response::*;
 pub use sign::*;
@@ -0,0 +1,424 @@
+use super::{
+    Channel, Client, Data, Error, OrderbookDeltaResponse, OrderbookSnapshot,
+    OrderbookSnapshotResponse, Result,
+};
+use crate::{order::Side, Price, Qty};
+use futures_util::{
+    ready,
+    task::{Context, Poll},
+    Stream, StreamExt,
+};
+use std::collections::{BTreeMap, HashMap};
+use std::pin::Pin;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OrderBookLevel {
+    /// Level id
+    pub id: i64,
+    /// Price of the level
+    pub price: Price,
+    /// Total size at the level
+    pub size: Qty,
+}
+
+/// A local order book, maintained from orderbook snapshots and deltas.
+///
+/// Bybit doesn't guarantee `cross_seq` to be contiguous, only increasing. A delta that isn't
+/// newer than the book is ignored, while a delta that doesn't match the book (e.g. updating or
+/// deleting an unknown level) puts the book out of sync until the next snapshot is applied.
+#[derive(Clone, Debug)]
+pub struct OrderBook {
+    symbol: String,
+    bids: BTreeMap<Price, OrderBookLevel>,
+    asks: BTreeMap<Price, OrderBookLevel>,
+    ids: HashMap<i64, (Side, Price)>,
+    cross_seq: Option<i64>,
+    timestamp_e6: i64,
+}
+
+impl OrderBook {
+    /// Create an empty order book.
+    /// - `symbol` - The symbol of the order book.
+    pub fn new(symbol: &str) -> Self {
+        OrderBook {
+            symbol: symbol.to_owned(),
+            bids: BTreeMap::new(),
+            asks: BTreeMap::new(),
+            ids: HashMap::new(),
+            cross_seq: None,
+            timestamp_e6: 0,
+        }
+    }
+
+    /// Returns the symbol of this order book.
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    /// Returns the cross sequence of the last applied update, or `None` if the book is out of sync.
+    pub fn cross_seq(&self) -> Option<i64> {
+        self.cross_seq
+    }
+
+    /// Returns the timestamp (in microseconds) of the last applied update.
+    pub fn timestamp_e6(&self) -> i64 {
+        self.timestamp_e6
+    }
+
+    /// Returns `true` if a snapshot has been applied and the book hasn't gone out of sync since.
+    pub fn is_synced(&self) -> bool {
+        self.cross_seq.is_some()
+    }
+
+    /// Clear the book, a new snapshot is required before deltas can be applied.
+    pub fn clear(&mut self) {
+        self.bids.clear();
+        self.asks.clear();
+        self.ids.clear();
+        self.cross_seq = None;
+    }
+
+    /// Replace the contents of the book with the given snapshot.
+    /// - `snapshot` - The snapshot to apply.
+    pub fn apply_snapshot(&mut self, snapshot: &OrderbookSnapshotResponse) {
+        self.clear();
+        for row in snapshot.data.iter() {
+            self.insert(row);
+        }
+        self.cross_seq = Some(snapshot.cross_seq);
+        self.timestamp_e6 = snapshot.timestamp_e6;
+    }
+
+    /// Apply the given delta to the book.
+    /// Returns `Ok(false)` if the delta is older than the book and was ignored.
+    /// - `delta` - The delta to apply.
+    pub fn apply_delta(&mut self, delta: &OrderbookDeltaResponse) -> Result<bool> {
+        let cross_seq = match self.cross_seq {
+            Some(cross_seq) => cross_seq,
+            None => return Err(Error::OrderBookOutOfSync(self.symbol.clone())),
+        };
+        if delta.cross_seq <= cross_seq {
+            return Ok(false);
+        }
+
+        let applied = delta.data.delete.iter().all(|row| self.remove(row.id))
+            && delta.data.update.iter().all(|row| self.update(row))
+            && delta.data.insert.iter().all(|row| self.insert(row));
+        if !applied {
+            self.clear();
+            return Err(Error::OrderBookOutOfSync(self.symbol.clone()));
+        }
+
+        self.cross_seq = Some(delta.cross_seq);
+        self.timestamp_e6 = delta.timestamp_e6;
+        Ok(true)
+    }
+
+    /// Returns the bids, best (highest) price first.
+    pub fn bids(&self) -> impl Iterator<Item = &OrderBookLevel> {
+        self.bids.values().rev()
+    }
+
+    /// Returns the asks, best (lowest) price first.
+    pub fn asks(&self) -> impl Iterator<Item = &OrderBookLevel> {
+        self.asks.values()
+    }
+
+    /// Returns the best bid.
+    pub fn best_bid(&self) -> Option<&OrderBookLevel> {
+        self.bids().next()
+    }
+
+    /// Returns the best ask.
+    pub fn best_ask(&self) -> Option<&OrderBookLevel> {
+        self.asks().next()
+    }
+
+    /// Returns the best `n` levels on the given side, best price first.
+    /// - `side` - `Buy` for the bids, `Sell` for the asks.
+    /// - `n` - The number of levels.
+    pub fn depth(&self, side: Side, n: usize) -> Vec<OrderBookLevel> {
+        match side {
+            Side::Buy => self.bids().take(n).copied().collect(),
+            Side::Sell => self.asks().take(n).copied().collect(),
+        }
+    }
+
+    /// Returns the price halfway between the best bid and the best ask.
+    pub fn mid(&self) -> Option<Price> {
+        let bid = self.best_bid()?;
+        let ask = self.best_ask()?;
+        Some((bid.price + ask.price) / rust_decimal::Decimal::TWO)
+    }
+
+    /// Returns the difference between the best ask and the best bid.
+    pub fn spread(&self) -> Option<Price> {
+        let bid = self.best_bid()?;
+        let ask = self.best_ask()?;
+        Some(ask.price - bid.price)
+    }
+
+    /// Returns the total size of the levels on the given side priced at or better than `price`.
+    /// - `side` - `Buy` for the bids, `Sell` for the asks.
+    /// - `price` - The price up to which the size is accumulated.
+    pub fn cumulative_size(&self, side: Side, price: Price) -> Qty {
+        match side {
+            Side::Buy => self
+                .bids()
+                .take_while(|level| level.price >= price)
+                .fold(Qty::ZERO, |size, level| size + level.size),
+            Side::Sell => self
+                .asks()
+                .take_while(|level| level.price <= price)
+                .fold(Qty::ZERO, |size, level| size + level.size),
+        }
+    }
+
+    fn side_mut(&mut self, side: Side) -> &mut BTreeMap<Price, OrderBookLevel> {
+        match side {
+            Side::Buy => &mut self.bids,
+            Side::Sell => &mut self.asks,
+        }
+    }
+
+    fn insert(&mut self, row: &OrderbookSnapshot) -> bool {
+        if self.ids.contains_key(&row.id) {
+            return false;
+        }
+        let key = row.price;
+        let level = OrderBookLevel {
+            id: row.id,
+            price: row.price,
+            size: row.size.unwrap_or_default(),
+        };
+        // A level inserted at an existing price replaces it, along with its id.
+        if let Some(replaced) = self.side_mut(row.side).insert(key, level) {
+            self.ids.remove(&replaced.id);
+        }
+        self.ids.insert(row.id, (row.side, key));
+        true
+    }
+
+    fn update(&mut self, row: &OrderbookSnapshot) -> bool {
+        let (side, key) = match self.ids.get(&row.id) {
+            Some(entry) => *entry,
+            None => return false,
+        };
+        let size = row.size;
+        match self.side_mut(side).get_mut(&key) {
+            Some(level) => {
+                level.size = size.unwrap_or(level.size);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn remove(&mut self, id: i64) -> bool {
+        match self.ids.remove(&id) {
+            Some((side, key)) => self.side_mut(side).remove(&key).is_some(),
+            None => false,
+        }
+    }
+}
+
+/// Stream adaptor over a websocket client that maintains an order book per subscribed symbol
+/// and yields the updated book after every applied snapshot or delta.
+///
+/// Once a book goes out of sync, every delta received for it yields
+/// `Error::OrderBookOutOfSync` until a new snapshot arrives, e.g. after calling `resync`.
+pub struct OrderBookStream {
+    client: Client,
+    books: HashMap<String, OrderBook>,
+}
+
+impl OrderBookStream {
+    /// Create a new order book stream.
+    /// - `client` - The client, subscribed to `OrderBook25` and/or `OrderBook200` channels.
+    pub fn new(client: Client) -> Self {
+        OrderBookStream {
+            client,
+            books: HashMap::new(),
+        }
+    }
+
+    /// Returns the order book for the given symbol.
+    /// - `symbol` - The symbol of the order book.
+    pub fn book(&self, symbol: &str) -> Option<&OrderBook> {
+        self.books.get(symbol)
+    }
+
+    /// Returns the underlying client.
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// Returns the underlying client.
+    pub fn client_mut(&mut self) -> &mut Client {
+        &mut self.client
+    }
+
+    /// Consumes the stream, returning the underlying client.
+    pub fn into_inner(self) -> Client {
+        self.client
+    }
+
+    /// Request a fresh snapshot for the given symbol by resubscribing to its order book channel.
+    /// - `symbol` - The symbol of the order book.
+    pub async fn resync(&mut self, symbol: &str) -> Result<()> {
+        let channel = self
+            .client
+            .channels()
+            .iter()
+            .find(|channel| match channel {
+                Channel::OrderBook25(s) | Channel::OrderBook200(s) => s == symbol,
+                _ => false,
+            })
+            .cloned()
+            .ok_or_else(|| Error::NotSubscribed(Channel::OrderBook25(symbol.to_owned())))?;
+
+        if let Some(book) = self.books.get_mut(symbol) {
+            book.clear();
+        }
+        self.client
+            .unsubscribe(std::slice::from_ref(&channel))
+            .await?;
+        self.client.subscribe(&[channel]).await
+    }
+}
+
+impl Stream for OrderBookStream {
+    type Item = Result<OrderBook>;
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            let data = match ready!(self.client.poll_next_unpin(cx)) {
+                Some(Ok(data)) => data,
+                Some(Err(e)) => return Poll::Ready(Some(Err(e))),
+                None => return Poll::Ready(None),
+            };
+            match data {
+                Data::OrderbookSnapshot(res) => {
+                    let symbol = topic_symbol(&res.topic);
+                    let book = self
+                        .books
+                        .entry(symbol.to_owned())
+                        .or_insert_with(|| OrderBook::new(symbol));
+                    book.apply_snapshot(&res);
+                    return Poll::Ready(Some(Ok(book.clone())));
+                }
+                Data::OrderbookDelta(res) => {
+                    let book = match self.books.get_mut(topic_symbol(&res.topic)) {
+                        Some(book) => book,
+                        None => continue,
+                    };
+                    match book.apply_delta(&res) {
+                        Ok(true) => return Poll::Ready(Some(Ok(book.clone()))),
+                        Ok(false) => continue,
+                        Err(e) => return Poll::Ready(Some(Err(e))),
+                    }
+                }
+                Data::Reconnected => self.books.values_mut().for_each(OrderBook::clear),
+                _ => continue,
+            }
+        }
+    }
+}
+
+fn topic_symbol(topic: &str) -> &str {
+    topic.rsplit('.').next().unwrap_or(topic)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn price(value: &str) -> Price {
+        value.parse().unwrap()
+    }
+
+    const SNAPSHOT: &str = r#"{"topic":"orderBookL2_25.BTCUSD","type":"snapshot","data":[{"price":"2999.00","symbol":"BTCUSD","id":29990000,"side":"Buy","size":9},{"price":"2998.50","symbol":"BTCUSD","id":29985000,"side":"Buy","size":3},{"price":"3001.00","symbol":"BTCUSD","id":30010000,"side":"Sell","size":10},{"price":"3002.00","symbol":"BTCUSD","id":30020000,"side":"Sell","size":5}],"cross_seq":11518,"timestamp_e6":1555647164875373}"#;
+
+    const DELTA: &str = r#"{"topic":"orderBookL2_25.BTCUSD","type":"delta","data":{"delete":[{"price":"3001.00","symbol":"BTCUSD","id":30010000,"side":"Sell"}],"update":[{"price":"2999.00","symbol":"BTCUSD","id":29990000,"side":"Buy","size":8}],"insert":[{"price":"3000.00","symbol":"BTCUSD","id":30000000,"side":"Buy","size":2}],"transactTimeE6":0},"cross_seq":11519,"timestamp_e6":1555647221331673}"#;
+
+    fn synced_book() -> OrderBook {
+        let snapshot: OrderbookSnapshotResponse = serde_json::from_str(SNAPSHOT).unwrap();
+        let mut book = OrderBook::new("BTCUSD");
+        book.apply_snapshot(&snapshot);
+        book
+    }
+
+    #[test]
+    fn snapshot() {
+        let book = synced_book();
+        assert!(book.is_synced());
+        assert_eq!(book.cross_seq(), Some(11518));
+        assert_eq!(book.best_bid().unwrap().price, price("2999.0"));
+        assert_eq!(book.best_ask().unwrap().price, price("3001.0"));
+        assert_eq!(book.mid(), Some(price("3000.0")));
+        assert_eq!(book.spread(), Some(price("2.0")));
+        assert_eq!(book.depth(Side::Buy, 5).len(), 2);
+        assert_eq!(
+            book.cumulative_size(Side::Buy, price("2998.5")),
+            Qty::from(12)
+        );
+        assert_eq!(
+            book.cumulative_size(Side::Sell, price("3001.0")),
+            Qty::from(10)
+        );
+    }
+
+    #[test]
+    fn delta() {
+        let mut book = synced_book();
+        let delta: OrderbookDeltaResponse = serde_json::from_str(DELTA).unwrap();
+        assert!(book.apply_delta(&delta).unwrap());
+        assert_eq!(book.cross_seq(), Some(11519));
+        assert_eq!(book.best_bid().unwrap().price, price("3000.0"));
+        assert_eq!(book.best_bid().unwrap().size, Qty::from(2));
+        assert_eq!(book.best_ask().unwrap().price, price("3002.0"));
+        assert_eq!(
+            book.cumulative_size(Side::Buy, price("2999.0")),
+            Qty::from(10)
+        );
+
+        // Applying the same delta again is a no-op.
+        assert!(!book.apply_delta(&delta).unwrap());
+        assert_eq!(book.best_bid().unwrap().size, Qty::from(2));
+    }
+
+    #[test]
+    fn out_of_sync() {
+        let delta: OrderbookDeltaResponse = serde_json::from_str(DELTA).unwrap();
+        let mut book = OrderBook::new("BTCUSD");
+        assert!(book.apply_delta(&delta).is_err());
+
+        let mut book = synced_book();
+        let mut delta = delta;
+        delta.data.update[0].id = 1;
+        assert!(book.apply_delta(&delta).is_err());
+        assert!(!book.is_synced());
+        assert!(book.best_bid().is_none());
+    }
+
+    #[test]
+    fn insert_at_existing_price() {
+        let mut book = synced_book();
+        let mut delta: OrderbookDeltaResponse = serde_json::from_str(DELTA).unwrap();
+        delta.data.delete.clear();
+        delta.data.update.clear();
+        delta.data.insert[0].id = 29990001;
+        delta.data.insert[0].price = price("2999.00");
+        assert!(book.apply_delta(&delta).unwrap());
+        assert_eq!(book.best_bid().unwrap().id, 29990001);
+        assert_eq!(book.best_bid().unwrap().size, Qty::from(2));
+
+        // The replaced id no longer refers to the level at that price.
+        let mut row = delta.data.insert[0].clone();
+        row.id = 29990000;
+        delta.data.delete = vec![row];
+        delta.data.insert.clear();
+        delta.cross_seq += 1;
+        assert!(book.apply_delta(&delta).is_err());
+        assert!(!book.is_synced());
+    }
+}
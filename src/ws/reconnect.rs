@@ -0,0 +1,98 @@
+use rand::Rng;
+use std::time::Duration;
+
+/// Policy describing how a disconnected client should attempt to reconnect.
+#[derive(Clone, Debug)]
+pub struct ReconnectPolicy {
+    /// The maximum number of consecutive reconnect attempts, `None` for unlimited.
+    pub max_attempts: Option<u32>,
+    /// The delay before the first reconnect attempt.
+    pub initial_backoff: Duration,
+    /// The upper bound of the delay between two reconnect attempts.
+    pub max_backoff: Duration,
+    /// The factor the delay is multiplied with after every failed attempt.
+    pub multiplier: f64,
+    /// The fraction (between `0.0` and `1.0`) by which the delay is randomly spread.
+    pub jitter: f64,
+}
+
+impl ReconnectPolicy {
+    /// Returns `true` if another reconnect attempt is allowed.
+    /// - `attempt` - The number of attempts made so far.
+    pub fn allows(&self, attempt: u32) -> bool {
+        self.max_attempts.is_none_or(|max| attempt < max)
+    }
+
+    /// Returns the delay to wait before the given reconnect attempt.
+    /// - `attempt` - The number of attempts made so far.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exp = self.multiplier.max(1.0).powi(attempt.min(64) as i32);
+        let delay = (self.initial_backoff.as_secs_f64() * exp).min(self.max_backoff.as_secs_f64());
+        let jitter = self.jitter.clamp(0.0, 1.0);
+        let factor = if jitter > 0.0 {
+            rand::thread_rng().gen_range(1.0 - jitter..=1.0 + jitter)
+        } else {
+            1.0
+        };
+        Duration::from_secs_f64(delay * factor)
+    }
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        ReconnectPolicy {
+            max_attempts: Some(10),
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: 0.2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff() {
+        let policy = ReconnectPolicy {
+            jitter: 0.0,
+            ..Default::default()
+        };
+        assert_eq!(policy.backoff(0), Duration::from_millis(500));
+        assert_eq!(policy.backoff(1), Duration::from_secs(1));
+        assert_eq!(policy.backoff(3), Duration::from_secs(4));
+        assert_eq!(policy.backoff(20), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn backoff_jitter() {
+        let policy = ReconnectPolicy {
+            jitter: 0.5,
+            ..Default::default()
+        };
+        for _ in 0..100 {
+            let delay = policy.backoff(1);
+            assert!(delay >= Duration::from_millis(500));
+            assert!(delay <= Duration::from_millis(1500));
+        }
+    }
+
+    #[test]
+    fn allows() {
+        let policy = ReconnectPolicy {
+            max_attempts: Some(2),
+            ..Default::default()
+        };
+        assert!(policy.allows(0));
+        assert!(policy.allows(1));
+        assert!(!policy.allows(2));
+
+        let policy = ReconnectPolicy {
+            max_attempts: None,
+            ..Default::default()
+        };
+        assert!(policy.allows(u32::MAX));
+    }
+}
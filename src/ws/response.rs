@@ -1,15 +1,19 @@
-use crate::deserialize::{optional_string_or_number, string_or_number};
+use crate::deserialize::{optional_string_or_number, rfc3339_millis, string_or_number};
 use crate::{
     order::Side, ticker::TickDirection, trade::TradeId, CancelType, CreateType, ExecId, ExecType,
-    OrderId, OrderLinkId, OrderStatus, OrderType, StopOrderStatus, StopOrderType, TimeInForce,
-    TriggerPrice, UserId,
+    Interval, Kline, LiquidityType, OrderId, OrderLinkId, OrderStatus, OrderType, Price,
+    PublicTrade, Qty, StopOrderStatus, StopOrderType, TimeInForce, TriggerPrice, UserId,
 };
 use serde::Deserialize;
 
+// Positions and executions are shared with the REST API.
+pub use crate::execution::Execution;
+pub use crate::position::{Position, PositionStatus};
+
 #[derive(Debug, Clone)]
 pub enum Data {
-    OrderbookSnapshot(OrderbookSnapshot),
-    OrderbookDelta(OrderbookDelta),
+    OrderbookSnapshot(OrderbookSnapshotResponse),
+    OrderbookDelta(OrderbookDeltaResponse),
     Trade(Trade),
     Insurance(Insurance),
     InstrumentInfoSnapshot(InstrumentInfoSnapshot),
@@ -20,20 +24,22 @@ pub enum Data {
     Execution(Execution),
     Order(Order),
     StopOrder(StopOrder),
+    /// The client reconnected after the connection dropped.
+    /// Any local state (e.g. order books) should be resynchronized.
+    Reconnected,
 }
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct OrderbookSnapshot {
     pub id: i64,
-    #[serde(deserialize_with = "string_or_number")]
-    pub price: f64,
+    pub price: Price,
     pub symbol: String,
     pub side: Side,
-    pub size: Option<i64>,
+    pub size: Option<Qty>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
-pub(super) struct OrderbookSnapshotResponse {
+pub struct OrderbookSnapshotResponse {
     pub topic: String,
     pub data: Vec<OrderbookSnapshot>,
     pub cross_seq: i64,
@@ -43,9 +49,9 @@ pub(super) struct OrderbookSnapshotResponse {
 #[derive(Deserialize, Debug, Clone)]
 pub struct Trade {
     pub side: Side,
-    pub size: i64,
+    pub size: Qty,
     pub symbol: String,
-    pub price: f64,
+    pub price: Price,
     pub tick_direction: TickDirection,
     pub trade_id: TradeId,
     pub timestamp: String,
@@ -53,9 +59,21 @@ pub struct Trade {
     pub cross_seq: i64,
 }
 
+impl From<Trade> for PublicTrade {
+    fn from(trade: Trade) -> Self {
+        PublicTrade {
+            trade_id: trade.trade_id,
+            symbol: trade.symbol,
+            side: trade.side,
+            price: trade.price,
+            size: trade.size,
+            trade_time_ms: trade.trade_time_ms,
+        }
+    }
+}
+
 #[derive(Deserialize, Debug, Clone)]
 pub(super) struct TradeResponse {
-    pub topic: String,
     pub data: Vec<Trade>,
 }
 
@@ -68,7 +86,6 @@ pub struct Insurance {
 
 #[derive(Deserialize, Debug, Clone)]
 pub(super) struct InsuranceResponse {
-    pub topic: String,
     pub data: Vec<Insurance>,
 }
 
@@ -122,17 +139,36 @@ pub struct InstrumentInfoDelta {
 pub struct KlineV2 {
     pub start: i64,
     pub end: i64,
-    pub open: f64,
-    pub close: f64,
-    pub high: f64,
-    pub low: f64,
-    pub volume: f64,
+    pub open: Price,
+    pub close: Price,
+    pub high: Price,
+    pub low: Price,
+    pub volume: Qty,
     pub turnover: f64,
     pub confirm: bool,
     pub cross_seq: i64,
     pub timestamp: String,
 }
 
+impl KlineV2 {
+    /// Convert to a `Kline`, as returned by `rest::FetchKlines`.
+    /// - `symbol` - The symbol of the subscribed channel.
+    /// - `interval` - The interval of the subscribed channel.
+    pub fn to_kline(&self, symbol: &str, interval: Interval) -> Kline {
+        Kline {
+            symbol: symbol.to_owned(),
+            interval,
+            open_time: self.start,
+            open: self.open,
+            high: self.high,
+            low: self.low,
+            close: self.close,
+            volume: self.volume,
+            turnover: self.turnover,
+        }
+    }
+}
+
 #[derive(Deserialize, Debug, Clone)]
 pub struct OrderbookDelta {
     pub delete: Vec<OrderbookSnapshot>,
@@ -143,7 +179,7 @@ pub struct OrderbookDelta {
 }
 
 #[derive(Deserialize, Debug, Clone)]
-pub(super) struct OrderbookDeltaResponse {
+pub struct OrderbookDeltaResponse {
     pub topic: String,
     pub data: OrderbookDelta,
     pub cross_seq: i64,
@@ -152,10 +188,7 @@ pub(super) struct OrderbookDeltaResponse {
 
 #[derive(Deserialize, Debug, Clone)]
 pub(super) struct InstrumentInfoSnapshotResponse {
-    pub topic: String,
     pub data: InstrumentInfoSnapshot,
-    pub cross_seq: i64,
-    pub timestamp_e6: i64,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -167,17 +200,12 @@ pub struct InstrumentInfoDeltaData {
 
 #[derive(Deserialize, Debug, Clone)]
 pub(super) struct InstrumentInfoDeltaResponse {
-    pub topic: String,
     pub data: InstrumentInfoDeltaData,
-    pub cross_seq: i64,
-    pub timestamp_e6: i64,
 }
 
 #[derive(Deserialize, Debug, Clone)]
 pub(super) struct KlineV2Response {
-    pub topic: String,
     pub data: Vec<KlineV2>,
-    pub timestamp_e6: i64,
 }
 
 #[derive(Deserialize, Debug)]
@@ -196,8 +224,6 @@ pub struct Request {
 #[derive(Deserialize, Debug, Clone)]
 pub(super) struct RequestResponse {
     pub success: bool,
-    pub ret_msg: String,
-    pub conn_id: String,
     pub request: Request,
 }
 
@@ -207,89 +233,63 @@ pub enum PositionAction {
     Update,
 }
 
-#[derive(Deserialize, Debug, Clone)]
-pub enum PositionStatus {
-    Normal,
-    Liq,
-    Adl,
-}
-
-#[derive(Deserialize, Debug, Clone)]
-pub struct Position {
-    pub user_id: UserId,
-    pub symbol: String,
-    pub size: i64,
-    pub side: Side,
-    pub position_value: f64,
-    pub entry_price: f64,
-    #[serde(deserialize_with = "string_or_number")]
-    pub liq_price: f64,
-    #[serde(deserialize_with = "string_or_number")]
-    pub bust_price: f64,
-    #[serde(deserialize_with = "string_or_number")]
-    pub leverage: f64,
-    #[serde(deserialize_with = "string_or_number")]
-    pub order_margin: f64,
-    #[serde(deserialize_with = "string_or_number")]
-    pub position_margin: f64,
-    #[serde(deserialize_with = "string_or_number")]
-    pub available_balance: f64,
-    #[serde(deserialize_with = "string_or_number")]
-    pub take_profit: f64,
-    pub tp_trigger_by: TriggerPrice,
-    #[serde(deserialize_with = "string_or_number")]
-    pub stop_loss: f64,
-    pub sl_trigger_by: TriggerPrice,
-    #[serde(deserialize_with = "string_or_number")]
-    pub realised_pnl: f64,
-    #[serde(deserialize_with = "string_or_number")]
-    pub trailing_stop: f64,
-    #[serde(deserialize_with = "string_or_number")]
-    pub trailing_active: f64,
-    #[serde(deserialize_with = "string_or_number")]
-    pub wallet_balance: f64,
-    pub risk_id: i64,
-    #[serde(deserialize_with = "string_or_number")]
-    pub occ_closing_fee: f64,
-    #[serde(deserialize_with = "string_or_number")]
-    pub occ_funding_fee: f64,
-    pub auto_add_margin: i64,
-    #[serde(deserialize_with = "string_or_number")]
-    pub cum_realised_pnl: f64,
-    pub position_status: PositionStatus,
-    pub position_seq: i64,
-}
-
 #[derive(Deserialize, Debug, Clone)]
 pub(super) struct PositionResponse {
-    pub topic: String,
-    pub action: PositionAction,
     pub data: Vec<Position>,
 }
 
+/// An execution as sent by the `Execution` channel, converted into the shared `Execution`.
 #[derive(Deserialize, Debug, Clone)]
-pub struct Execution {
+pub(super) struct ExecutionMessage {
     pub symbol: String,
     pub side: Side,
     pub order_id: OrderId,
     pub exec_id: ExecId,
     pub order_link_id: OrderLinkId,
-    #[serde(deserialize_with = "string_or_number")]
-    pub price: f64,
-    pub order_qty: i64,
+    pub price: Price,
+    pub order_qty: Qty,
     pub exec_type: ExecType,
-    pub exec_qty: i64,
+    pub exec_qty: Qty,
     #[serde(deserialize_with = "string_or_number")]
     pub exec_fee: f64,
-    pub leaves_qty: i64,
+    pub leaves_qty: Qty,
     pub is_maker: bool,
-    pub trade_time: String,
+    #[serde(rename = "trade_time", deserialize_with = "rfc3339_millis")]
+    pub trade_time_ms: i64,
+}
+
+impl From<ExecutionMessage> for Execution {
+    fn from(execution: ExecutionMessage) -> Self {
+        Execution {
+            exec_id: execution.exec_id,
+            order_id: execution.order_id,
+            order_link_id: execution.order_link_id,
+            symbol: execution.symbol,
+            side: execution.side,
+            order_type: None,
+            order_price: None,
+            order_qty: execution.order_qty,
+            exec_type: execution.exec_type,
+            exec_price: execution.price,
+            exec_qty: execution.exec_qty,
+            exec_value: None,
+            exec_fee: execution.exec_fee,
+            fee_rate: None,
+            leaves_qty: execution.leaves_qty,
+            closed_size: None,
+            last_liquidity_ind: if execution.is_maker {
+                LiquidityType::AddedLiquidity
+            } else {
+                LiquidityType::RemovedLiquidity
+            },
+            trade_time_ms: execution.trade_time_ms,
+        }
+    }
 }
 
 #[derive(Deserialize, Debug, Clone)]
 pub(super) struct ExecutionResponse {
-    pub topic: String,
-    pub data: Vec<Execution>,
+    pub data: Vec<ExecutionMessage>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -301,29 +301,22 @@ pub struct Order {
     pub symbol: String,
     pub side: Side,
     pub order_type: OrderType,
-    #[serde(deserialize_with = "string_or_number")]
-    pub price: f64,
-    pub qty: f64,
+    pub price: Price,
+    pub qty: Qty,
     pub time_in_force: TimeInForce,
     pub create_type: Option<CreateType>,
     pub cancel_type: Option<CancelType>,
     pub order_status: OrderStatus,
-    #[serde(deserialize_with = "string_or_number")]
-    pub leaves_qty: f64,
-    #[serde(deserialize_with = "string_or_number")]
-    pub cum_exec_qty: f64,
+    pub leaves_qty: Qty,
+    pub cum_exec_qty: Qty,
     #[serde(deserialize_with = "optional_string_or_number", default)]
     pub cum_exec_value: Option<f64>,
     #[serde(deserialize_with = "optional_string_or_number", default)]
     pub cum_exec_fee: Option<f64>,
-    #[serde(deserialize_with = "string_or_number")]
-    pub take_profit: f64,
-    #[serde(deserialize_with = "string_or_number")]
-    pub stop_loss: f64,
-    #[serde(deserialize_with = "string_or_number")]
-    pub trailing_stop: f64,
-    #[serde(deserialize_with = "string_or_number")]
-    pub trailing_active: f64,
+    pub take_profit: Price,
+    pub stop_loss: Price,
+    pub trailing_stop: Price,
+    pub trailing_active: Price,
     pub reduce_only: bool,
     pub close_on_trigger: bool,
     pub timestamp: String,
@@ -331,7 +324,6 @@ pub struct Order {
 
 #[derive(Deserialize, Debug, Clone)]
 pub(super) struct OrderResponse {
-    pub topic: String,
     pub data: Vec<Order>,
 }
 
@@ -343,24 +335,21 @@ pub struct StopOrder {
     pub symbol: String,
     pub side: Side,
     pub order_type: OrderType,
-    #[serde(deserialize_with = "string_or_number")]
-    pub price: f64,
-    pub qty: f64,
+    pub price: Price,
+    pub qty: Qty,
     pub time_in_force: TimeInForce,
     pub create_type: CreateType,
     pub cancel_type: CancelType,
     pub order_status: StopOrderStatus,
     pub stop_order_type: StopOrderType,
     pub trigger_by: TriggerPrice,
-    #[serde(deserialize_with = "string_or_number")]
-    pub trigger_price: f64,
+    pub trigger_price: Price,
     pub close_on_trigger: bool,
     pub timestamp: String,
 }
 
 #[derive(Deserialize, Debug, Clone)]
 pub(super) struct StopOrderResponse {
-    pub topic: String,
     pub data: Vec<StopOrder>,
 }
 
@@ -369,16 +358,13 @@ pub(super) struct StopOrderResponse {
 pub struct Liquidation {
     pub symbol: String,
     pub side: Side,
-    #[serde(deserialize_with = "string_or_number")]
-    pub price: f64,
-    #[serde(deserialize_with = "string_or_number")]
-    pub qty: f64,
+    pub price: Price,
+    pub qty: Qty,
     pub time: i64,
 }
 
 #[derive(Deserialize, Debug, Clone)]
 pub(super) struct LiquidationResponse {
-    pub topic: String,
     pub data: Liquidation,
 }
 
@@ -399,3 +385,39 @@ pub(super) enum Response {
     Order(OrderResponse),
     StopOrder(StopOrderResponse),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fractional_trade_size() {
+        let trade: Trade = serde_json::from_str(
+            r#"{"symbol":"BTCUSD","tick_direction":"PlusTick","price":"9499.50","size":0.5,
+                "timestamp":"2020-01-12T16:59:59.000Z","trade_time_ms":1578848399000,
+                "side":"Sell","trade_id":"dc70d9ce-1f2f-5c32-9a3e-9a37b8d2d4d8","cross_seq":1}"#,
+        )
+        .unwrap();
+        let trade = PublicTrade::from(trade);
+        assert_eq!(trade.size, "0.5".parse().unwrap());
+        assert_eq!(trade.trade_time_ms, 1578848399000);
+    }
+
+    #[test]
+    fn execution() {
+        let execution: ExecutionMessage = serde_json::from_str(
+            r#"{"symbol":"BTCUSD","side":"Buy","order_id":"xxxxxxxx-xxxx-xxxx-9a8f-4a973eb5c418",
+                "exec_id":"xxxxxxxx-xxxx-xxxx-8b66-c3d2fcd352f6","order_link_id":"",
+                "price":"8300","order_qty":1,"exec_type":"Trade","exec_qty":1,
+                "exec_fee":"0.00000009","leaves_qty":0,"is_maker":false,
+                "trade_time":"2020-01-14T14:07:23.629Z"}"#,
+        )
+        .unwrap();
+        let execution = Execution::from(execution);
+        assert_eq!(execution.exec_price, Price::from(8300));
+        assert_eq!(execution.exec_qty, Qty::from(1));
+        assert!(!execution.is_maker());
+        assert_eq!(execution.trade_time_ms, 1579010843629);
+        assert!(execution.order_type.is_none());
+    }
+}